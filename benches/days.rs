@@ -0,0 +1,65 @@
+//! Benchmarks every registered day's parser and each of its parts separately, on the day's
+//! examples and (when present) its real input.
+//!
+//! Run with `cargo bench`, or e.g. `cargo bench -- day07/input` for one day's input. To compare
+//! against an earlier run, save a baseline first with `cargo bench -- --save-baseline before` and
+//! then run `cargo bench -- --baseline before`.
+
+use advent2023::advent::{self, SolverEntry};
+use advent2023::config::Config;
+use advent2023::examples;
+use advent2023::util::io;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use std::hint::black_box;
+use std::path::Path;
+use std::time::Duration;
+
+// The inputs to benchmark a day on: its examples, then its real input. Each comes with its name
+// and the parts it's valid for, since some examples only have answers for one part.
+fn inputs(config: &Config, entry: &SolverEntry) -> Vec<(String, Vec<String>, Vec<u32>)> {
+    let mut inputs = examples::for_day(config.year, entry.day, None)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|example| {
+            let parts = example.expected.keys().copied().collect();
+            (example.name.clone(), example.path(), parts)
+        })
+        .collect::<Vec<_>>();
+    let input_path = advent::input_path(config, entry.day);
+    if Path::new(&input_path).exists() {
+        inputs.push(("input".to_string(), input_path, vec![1, 2]));
+    }
+    inputs
+        .into_iter()
+        .map(|(name, path, parts)| (name, io::read_file_as_lines(&path).unwrap(), parts))
+        .collect()
+}
+
+fn bench_days(c: &mut Criterion) {
+    let config = Config::default();
+    for entry in advent::registry(config.year).iter() {
+        let mut group = c.benchmark_group(format!("day{:02}", entry.day));
+        // Some parts take seconds on the real input, so keep to criterion's minimum sample count
+        group.sample_size(10);
+        group.warm_up_time(Duration::from_millis(500));
+        for (name, input, parts) in inputs(&config, entry).iter() {
+            group.bench_with_input(BenchmarkId::new("parse", name), input, |b, input| {
+                b.iter(|| entry.create().parse(black_box(input)).unwrap())
+            });
+            let mut solver = entry.create();
+            solver.parse(input).unwrap();
+            for part in parts.iter() {
+                group.bench_function(BenchmarkId::new(format!("part{}", part), name), |b| {
+                    b.iter(|| match part {
+                        1 => solver.part_one().unwrap(),
+                        _ => solver.part_two().unwrap(),
+                    })
+                });
+            }
+        }
+        group.finish();
+    }
+}
+
+criterion_group!(benches, bench_days);
+criterion_main!(benches);
@@ -0,0 +1,45 @@
+//! Generates the solver registries from module declarations, so that declaring a module is all it
+//! takes to register it: `YEARS` from the `pub mod yYYYY;` lines in `src/advent/mod.rs`, and each
+//! year's `REGISTRY` from the `pub mod dayNN;` lines in its `src/advent/yYYYY/mod.rs`.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// The modules declared in `mod_path` whose names start with `prefix`, in order.
+fn declared_modules(mod_path: &Path, prefix: &str) -> Vec<String> {
+    fs::read_to_string(mod_path)
+        .unwrap()
+        .lines()
+        .filter_map(|line| line.strip_prefix("pub mod ")?.strip_suffix(';'))
+        .filter(|module| module.starts_with(prefix))
+        .map(String::from)
+        .collect()
+}
+
+fn main() {
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    // Also picks up new year directories and changes to any of their mod.rs files
+    println!("cargo:rerun-if-changed=src/advent");
+    let years = declared_modules(Path::new("src/advent/mod.rs"), "y");
+    let entries = years
+        .iter()
+        .map(|module| format!("{} => {}", &module[1..], module))
+        .collect::<Vec<_>>();
+    fs::write(
+        out_dir.join("years.rs"),
+        format!("register_years!({});\n", entries.join(", ")),
+    )
+    .unwrap();
+    for year_module in years.iter() {
+        let days = declared_modules(
+            &Path::new("src/advent").join(year_module).join("mod.rs"),
+            "day",
+        );
+        fs::write(
+            out_dir.join(format!("{}_registry.rs", year_module)),
+            format!("register_days!({});\n", days.join(", ")),
+        )
+        .unwrap();
+    }
+}
@@ -1,31 +1,339 @@
+use crate::config::Config;
+use crate::error::{InputError, Location};
+use crate::util::io;
 use anyhow::{format_err, Error};
+use itertools::Itertools;
+use log::info;
+use std::fmt;
+use std::time::{Duration, Instant};
 
-trait AdventSolver {
-    fn solve(&mut self, input_path: &str) -> Result<(), anyhow::Error>;
-}
-
-pub mod day01;
-pub mod day02;
-pub mod day03;
-pub mod day04;
-pub mod day05;
-pub mod day06;
-pub mod day07;
-pub mod day08;
-
-pub fn solve(day: u32) -> Result<(), Error> {
-    let mut solver: Box<dyn AdventSolver> = match day {
-        1 => Box::new(day01::Solver::default()),
-        2 => Box::new(day02::Solver::default()),
-        3 => Box::new(day03::Solver::default()),
-        4 => Box::new(day04::Solver::default()),
-        5 => Box::new(day05::Solver::default()),
-        6 => Box::new(day06::Solver::default()),
-        7 => Box::new(day07::Solver::default()),
-        8 => Box::new(day08::Solver::default()),
-        _ => {
-            return Err(format_err!("Day {} not implemented.", day));
+/// A solver is driven in two phases: `parse` turns the raw input lines into the day's domain
+/// types and stores them, then each part computes its answer from that parsed state. Solvers are
+/// `Send` so that days can be run on worker threads.
+pub trait AdventSolver: Send {
+    fn parse(&mut self, input: &[String]) -> Result<(), Error>;
+    fn part_one(&self) -> Result<Answer, Error>;
+    fn part_two(&self) -> Result<Answer, Error>;
+
+    /// Looks for problems in the input without solving it. Unlike `parse`, which stops at the
+    /// first problem, this should report every one it finds, including ones `parse` tolerates.
+    /// By default it only reports what `parse` rejects.
+    fn check(&mut self, input: &[String]) -> Vec<InputError> {
+        match self.parse(input) {
+            Ok(()) => Vec::new(),
+            Err(e) => match e.downcast::<InputError>() {
+                Ok(e) => vec![e],
+                Err(e) => vec![InputError::malformed(e, "")],
+            },
         }
+    }
+}
+
+/// A single answer produced by a solver, along with a human-readable description of what it is.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Answer {
+    pub label: String,
+    pub value: String,
+}
+
+impl Answer {
+    pub fn new<L: ToString, V: ToString>(label: L, value: V) -> Answer {
+        Answer {
+            label: label.to_string(),
+            value: value.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.label, self.value)
+    }
+}
+
+/// The results of one run of a solver, along with how long each phase took.
+#[derive(Clone, Debug)]
+pub struct Solution {
+    pub parse_time: Duration,
+    pub parts: Vec<PartSolution>,
+}
+
+#[derive(Clone, Debug)]
+pub struct PartSolution {
+    pub part: u32,
+    pub answer: Answer,
+    pub time: Duration,
+}
+
+impl Solution {
+    pub fn answers(&self) -> Vec<Answer> {
+        self.parts.iter().map(|p| p.answer.clone()).collect()
+    }
+}
+
+/// A registered solver: the day it solves, the puzzle's title, and how to construct it.
+pub struct SolverEntry {
+    pub day: u32,
+    pub title: &'static str,
+    create: fn() -> Box<dyn AdventSolver>,
+}
+
+/// Adds the `Solver` of each listed module to the year's `REGISTRY`. Each module provides its own
+/// `DAY` and `TITLE` constants, so they aren't repeated here. `build.rs` generates the invocation
+/// for each year's module (e.g. `y2023`) from the days it declares.
+macro_rules! register_days {
+    ($($module:ident),* $(,)?) => {
+        /// Every registered solver, in the order listed.
+        pub static REGISTRY: &[$crate::advent::SolverEntry] = &[
+            $($crate::advent::SolverEntry {
+                day: $module::DAY,
+                title: $module::TITLE,
+                create: || Box::new($module::Solver::default()),
+            },)*
+        ];
+    };
+}
+
+/// A year's registered solvers.
+pub struct Year {
+    pub year: u32,
+    pub registry: &'static [SolverEntry],
+}
+
+/// Adds each listed year's module (`yYYYY`) and its registry to `YEARS`. `build.rs` generates the
+/// invocation from the year modules declared below.
+macro_rules! register_years {
+    ($($year:literal => $module:ident),* $(,)?) => {
+        /// Every year with solvers, in the order listed.
+        pub static YEARS: &[Year] = &[$(Year { year: $year, registry: $module::REGISTRY },)*];
+    };
+}
+
+pub mod y2023;
+
+include!(concat!(env!("OUT_DIR"), "/years.rs"));
+
+impl SolverEntry {
+    /// Creates a fresh solver for this day.
+    pub fn create(&self) -> Box<dyn AdventSolver> {
+        (self.create)()
+    }
+}
+
+/// Returns every year that has solvers, in order.
+pub fn years() -> Vec<u32> {
+    YEARS.iter().map(|year| year.year).collect()
+}
+
+pub fn registry_for(year: u32) -> Result<&'static [SolverEntry], Error> {
+    YEARS
+        .iter()
+        .find(|entry| entry.year == year)
+        .map(|entry| entry.registry)
+        .ok_or(format_err!(
+            "No solvers for {} (years: {}).",
+            year,
+            years().iter().join(", ")
+        ))
+}
+
+/// The year's solvers, or none if it has no registry.
+pub fn registry(year: u32) -> &'static [SolverEntry] {
+    registry_for(year).unwrap_or(&[])
+}
+
+/// Returns every day of the year that has a solver, in order.
+pub fn implemented_days(year: u32) -> Vec<u32> {
+    registry(year).iter().map(|entry| entry.day).collect()
+}
+
+pub fn entry_for(year: u32, day: u32) -> Result<&'static SolverEntry, Error> {
+    registry_for(year)?
+        .iter()
+        .find(|entry| entry.day == day)
+        .ok_or(format_err!("Day {} of {} not implemented.", day, year))
+}
+
+/// Returns the default location of a day's puzzle input, in the configured year's subdirectory
+/// of the configured input directory.
+pub fn input_path(config: &Config, day: u32) -> String {
+    format!("{}/{}/day{:02}.txt", config.input_dir, config.year, day)
+}
+
+/// Solves a day of `year` against the given input path ("-" reads the puzzle input from stdin).
+/// If `part` is given, only that part is run; otherwise both are.
+pub fn solve(year: u32, day: u32, input_path: &str, part: Option<u32>) -> Result<Solution, Error> {
+    // Only read the input (which may block on stdin) once the day and part are known to be valid
+    let solver = solver_for(year, day)?;
+    let parts = parts_to_run(part)?;
+    run_solver(solver, day, &io::read_file_as_lines(input_path)?, parts)
+        .map_err(|e| locate(e, |location| location.file = Some(display_path(input_path))))
+}
+
+/// Like `solve`, but on input that has already been read, so that it can be run repeatedly.
+pub fn solve_input(
+    year: u32,
+    day: u32,
+    input: &[String],
+    part: Option<u32>,
+) -> Result<Solution, Error> {
+    run_solver(solver_for(year, day)?, day, input, parts_to_run(part)?)
+}
+
+// The parts to run: just `part` if given, otherwise both.
+fn parts_to_run(part: Option<u32>) -> Result<Vec<u32>, Error> {
+    match part {
+        None => Ok(vec![1, 2]),
+        Some(part @ (1 | 2)) => Ok(vec![part]),
+        Some(part) => Err(format_err!("Part {} does not exist.", part)),
+    }
+}
+
+// Parses the input and runs each of `parts`, timing both phases.
+fn run_solver(
+    mut solver: Box<dyn AdventSolver>,
+    day: u32,
+    input: &[String],
+    parts: Vec<u32>,
+) -> Result<Solution, Error> {
+    let start = Instant::now();
+    solver
+        .parse(input)
+        .map_err(|e| locate(e, |location| location.day = Some(day)))?;
+    let parse_time = start.elapsed();
+    info!("Day {} parsed in {:.2?}", day, parse_time);
+
+    let mut solution = Solution {
+        parse_time,
+        parts: Vec::new(),
     };
-    solver.solve(&format!("inputs/day{:02}.txt", day))
+    for part in parts {
+        let start = Instant::now();
+        let answer = match part {
+            1 => solver.part_one()?,
+            _ => solver.part_two()?,
+        };
+        let time = start.elapsed();
+        info!("Day {} part {} solved in {:.2?}", day, part, time);
+        solution.parts.push(PartSolution { part, answer, time });
+    }
+    Ok(solution)
+}
+
+/// Checks a day's input (see `AdventSolver::check`), returning every problem found.
+pub fn check(year: u32, day: u32, input_path: &str) -> Result<Vec<InputError>, Error> {
+    let mut solver = solver_for(year, day)?;
+    let input = io::read_file_as_lines(input_path)?;
+    let file = display_path(input_path);
+    let mut problems = solver.check(&input);
+    for problem in problems.iter_mut() {
+        let location = problem.location_mut();
+        location.day = Some(day);
+        location.file = Some(file.clone());
+    }
+    Ok(problems)
+}
+
+// How an input path is named in error messages.
+fn display_path(input_path: &str) -> String {
+    match input_path {
+        io::STDIN_PATH => "<stdin>".to_string(),
+        path => path.to_string(),
+    }
+}
+
+/// Fills in more of the location of an input error, leaving other errors alone.
+fn locate<F: FnOnce(&mut Location)>(e: Error, fill: F) -> Error {
+    match e.downcast::<InputError>() {
+        Ok(mut input_error) => {
+            fill(input_error.location_mut());
+            input_error.into()
+        }
+        Err(e) => e,
+    }
+}
+
+fn solver_for(year: u32, day: u32) -> Result<Box<dyn AdventSolver>, Error> {
+    Ok(entry_for(year, day)?.create())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day01_input() -> String {
+        input_path(&Config::default(), 1)
+    }
+
+    #[test]
+    fn test_solve_returns_answers() {
+        let answers = solve(2023, 1, &day01_input(), None).unwrap().answers();
+        assert_eq!(
+            vec![
+                Answer::new("Sum of calibration values", 54667),
+                Answer::new("Fixed sum of calibration values", 54203),
+            ],
+            answers
+        );
+        assert_eq!("Sum of calibration values: 54667", answers[0].to_string());
+    }
+
+    #[test]
+    fn test_solve_single_part() {
+        assert_eq!(
+            vec![Answer::new("Fixed sum of calibration values", 54203)],
+            solve(2023, 1, &day01_input(), Some(2)).unwrap().answers()
+        );
+        // The part is checked before the input is read
+        assert_eq!(
+            "Part 3 does not exist.",
+            solve(2023, 1, "inputs/2023/day99.txt", Some(3))
+                .err()
+                .unwrap()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn test_input_errors_are_located() {
+        let input = vec!["Game 1: 3 blue".to_string(), "Game two: 1 red".to_string()];
+        let error = solve_input(2023, 2, &input, None).err().unwrap();
+        let location = error.downcast_ref::<InputError>().unwrap().location();
+        assert_eq!((Some(2), 2), (location.day, location.line));
+        assert!(error.to_string().contains("2 | Game two: 1 red"));
+    }
+
+    #[test]
+    fn test_unimplemented_day() {
+        // Even though its input doesn't exist either
+        assert_eq!(
+            "Day 26 of 2023 not implemented.",
+            solve(2023, 26, "inputs/2023/day26.txt", None)
+                .err()
+                .unwrap()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn test_implemented_days() {
+        assert_eq!(vec![1, 2, 3, 4, 5, 6, 7, 8], implemented_days(2023)[..8]);
+    }
+
+    #[test]
+    fn test_registry_in_day_order() {
+        assert!(registry(2023).windows(2).all(|w| w[0].day < w[1].day));
+        assert_eq!("Camel Cards", entry_for(2023, 7).unwrap().title);
+        assert!(registry(1999).is_empty());
+    }
+
+    #[test]
+    fn test_registry_for() {
+        assert_eq!(y2023::REGISTRY.len(), registry_for(2023).unwrap().len());
+        assert_eq!(
+            "No solvers for 1999 (years: 2023).",
+            registry_for(1999).err().unwrap().to_string()
+        );
+        assert!(YEARS.windows(2).all(|w| w[0].year < w[1].year));
+    }
 }
@@ -0,0 +1,187 @@
+use crate::advent::{AdventSolver, Answer};
+use crate::error::{parse_number, InputError};
+use anyhow::Error;
+use lazy_static::lazy_static;
+use regex::Regex;
+
+pub const DAY: u32 = 2;
+pub const TITLE: &str = "Cube Conundrum";
+
+#[derive(Default)]
+pub struct Solver {
+    games: Vec<Game>,
+}
+
+lazy_static! {
+    static ref GAME_RE: Regex = Regex::new(r"Game (?P<id>\d+): (?P<results>.*)$").unwrap();
+    static ref RESULT_RE: Regex = Regex::new(r"(?P<count>\d+) (?P<color>red|green|blue)").unwrap();
+    static ref CUBES_RE: Regex = Regex::new(r"^(?P<count>\d+) (?P<color>\w+)$").unwrap();
+}
+
+const MAX_RED: u64 = 12;
+const MAX_GREEN: u64 = 13;
+const MAX_BLUE: u64 = 14;
+
+#[derive(Default)]
+pub struct Game {
+    pub id: u64,
+    pub results: Vec<GameResult>,
+}
+
+/// The cubes revealed in one handful during a game.
+#[derive(Default)]
+pub struct GameResult {
+    pub red_count: u64,
+    pub green_count: u64,
+    pub blue_count: u64,
+}
+
+impl AdventSolver for Solver {
+    fn parse(&mut self, input: &[String]) -> Result<(), Error> {
+        self.games = input
+            .iter()
+            .enumerate()
+            .map(|(i, line)| Game::parse(line).map_err(|e| e.on_line(i)))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(())
+    }
+
+    fn check(&mut self, input: &[String]) -> Vec<InputError> {
+        input
+            .iter()
+            .enumerate()
+            .flat_map(|(i, line)| Game::check(line).into_iter().map(move |e| e.on_line(i)))
+            .collect()
+    }
+
+    fn part_one(&self) -> Result<Answer, Error> {
+        Ok(Answer::new(
+            "Sum of valid game ids",
+            self.games
+                .iter()
+                .filter(|game| game.is_valid())
+                .map(|game| game.id)
+                .sum::<u64>(),
+        ))
+    }
+
+    fn part_two(&self) -> Result<Answer, Error> {
+        Ok(Answer::new(
+            "Sum of game cube \"powers\"",
+            self.games
+                .iter()
+                .map(|game| game.power_of_min_cube_set())
+                .sum::<u64>(),
+        ))
+    }
+}
+
+impl Game {
+    pub fn parse(s: &str) -> Result<Game, InputError> {
+        if let Some(caps) = GAME_RE.captures(s) {
+            let mut game = Game {
+                id: parse_number(s, caps.name("id").unwrap().range())?,
+                ..Default::default()
+            };
+            let results = caps.name("results").unwrap();
+            let mut offset = results.start();
+            for r in results.as_str().split(';') {
+                let mut result = GameResult::default();
+                for caps in RESULT_RE.captures_iter(r) {
+                    let count = caps.name("count").unwrap();
+                    let count = parse_number(s, offset + count.start()..offset + count.end())?;
+                    match caps.name("color").unwrap().as_str() {
+                        "red" => result.red_count = count,
+                        "green" => result.green_count = count,
+                        "blue" => result.blue_count = count,
+                        _ => unreachable!(),
+                    }
+                }
+                game.results.push(result);
+                offset += r.len() + 1;
+            }
+            Ok(game)
+        } else {
+            Err(InputError::malformed("Unparseable game", s))
+        }
+    }
+
+    /// Finds every problem in a game, including cubes `parse` skips over (e.g. "3 purple").
+    pub fn check(s: &str) -> Vec<InputError> {
+        let Some(caps) = GAME_RE.captures(s) else {
+            return vec![InputError::malformed("Unparseable game", s)];
+        };
+        let mut problems = Vec::new();
+        if let Err(e) = parse_number::<u64>(s, caps.name("id").unwrap().range()) {
+            problems.push(e);
+        }
+        let results = caps.name("results").unwrap();
+        let mut offset = results.start();
+        for cubes in results.as_str().split([';', ',']) {
+            let start = offset + cubes.len() - cubes.trim_start().len();
+            let end = offset + cubes.trim_end().len();
+            offset += cubes.len() + 1;
+            let Some(caps) = CUBES_RE.captures(&s[start..end]) else {
+                problems.push(InputError::malformed_at(
+                    "Expected a count and a color",
+                    s,
+                    start..end,
+                ));
+                continue;
+            };
+            let count = caps.name("count").unwrap();
+            if let Err(e) = parse_number::<u64>(s, start + count.start()..start + count.end()) {
+                problems.push(e);
+            }
+            let color = caps.name("color").unwrap();
+            if !["red", "green", "blue"].contains(&color.as_str()) {
+                problems.push(InputError::invalid_at(
+                    "Unknown color",
+                    s,
+                    start + color.start()..start + color.end(),
+                ));
+            }
+        }
+        problems
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.results.iter().all(|r| r.is_valid())
+    }
+
+    pub fn power_of_min_cube_set(&self) -> u64 {
+        let r = self.results.iter().map(|r| r.red_count).max().unwrap();
+        let g = self.results.iter().map(|r| r.green_count).max().unwrap();
+        let b = self.results.iter().map(|r| r.blue_count).max().unwrap();
+        r * g * b
+    }
+}
+
+impl GameResult {
+    pub fn is_valid(&self) -> bool {
+        self.red_count <= MAX_RED && self.green_count <= MAX_GREEN && self.blue_count <= MAX_BLUE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_finds_every_problem() {
+        let problems = Game::check("Game 1: 3 blue, 4 purple; 1 red, green; 2 grey");
+        let found = problems
+            .iter()
+            .map(|p| (p.message(), p.location().column, p.location().width))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                ("Unknown color", 19, 6),
+                ("Expected a count and a color", 34, 5),
+                ("Unknown color", 43, 4),
+            ],
+            found
+        );
+        assert!(Game::check("Game 1: 3 blue, 4 red; 1 green").is_empty());
+    }
+}
@@ -0,0 +1,224 @@
+use crate::advent::{AdventSolver, Answer};
+use crate::error::{parse_number, InputError};
+use anyhow::Error;
+use std::collections::{HashMap, HashSet};
+
+pub const DAY: u32 = 3;
+pub const TITLE: &str = "Gear Ratios";
+
+#[derive(Default)]
+pub struct Solver {
+    schematic: EngineSchematic,
+}
+
+impl AdventSolver for Solver {
+    fn parse(&mut self, input: &[String]) -> Result<(), Error> {
+        self.schematic = EngineSchematic::new(input)?;
+        Ok(())
+    }
+
+    fn check(&mut self, input: &[String]) -> Vec<InputError> {
+        let mut problems = Vec::new();
+        let width = input.first().map_or(0, |line| line.chars().count());
+        for (row, line) in input.iter().enumerate() {
+            let line_width = line.chars().count();
+            if line_width != width {
+                // Underline whatever is past the expected width, or the whole line if it's short
+                let span = match line.char_indices().nth(width) {
+                    Some((offset, _)) => offset..line.len(),
+                    None => 0..line.len(),
+                };
+                let message = format!(
+                    "Line is {} characters wide, but the first line is {}",
+                    line_width, width
+                );
+                problems.push(InputError::malformed_at(message, line, span).on_line(row));
+            }
+            if let Err(e) = EngineSchematic::new(std::slice::from_ref(line)) {
+                problems.push(e.on_line(row));
+            }
+        }
+        problems
+    }
+
+    fn part_one(&self) -> Result<Answer, Error> {
+        Ok(Answer::new(
+            "Sum of part numbers",
+            self.schematic.get_part_numbers().iter().sum::<u64>(),
+        ))
+    }
+
+    fn part_two(&self) -> Result<Answer, Error> {
+        Ok(Answer::new(
+            "Sum of gear ratios",
+            self.schematic.get_gear_ratios().iter().sum::<u64>(),
+        ))
+    }
+}
+
+#[derive(Default)]
+pub struct EngineSchematic {
+    /// Raw data
+    data: Vec<String>,
+
+    /// Position of symbols as tuple (row, column)
+    symbols: HashSet<(usize, usize)>,
+
+    /// Position of numbers as tuple (row, column, length, value)
+    numbers: Vec<(usize, usize, usize, u64)>,
+}
+
+impl EngineSchematic {
+    pub fn new(lines: &[String]) -> Result<EngineSchematic, InputError> {
+        let mut symbols = HashSet::new();
+        let mut numbers = Vec::new();
+        for (row, line) in lines.iter().enumerate() {
+            // The number being read, as (column, length, byte offset in the line)
+            let mut current_number: Option<(usize, usize, usize)> = None;
+            let mut finish_number = |num: Option<(usize, usize, usize)>, end: usize| {
+                if let Some((col, len, start)) = num {
+                    let value = parse_number(line, start..end).map_err(|e| e.on_line(row))?;
+                    numbers.push((row, col, len, value));
+                }
+                Ok::<(), InputError>(())
+            };
+            for (col, (offset, c)) in line.char_indices().enumerate() {
+                if c.is_ascii_digit() {
+                    if let Some(ref mut num) = current_number {
+                        // Current number length grows by one
+                        num.1 += 1;
+                    } else {
+                        current_number = Some((col, 1, offset));
+                    }
+                } else {
+                    finish_number(current_number.take(), offset)?;
+                    if c != '.' {
+                        symbols.insert((row, col));
+                    }
+                }
+            }
+            finish_number(current_number, line.len())?;
+        }
+
+        Ok(EngineSchematic {
+            data: lines.to_vec(),
+            symbols,
+            numbers,
+        })
+    }
+
+    pub fn get_part_numbers(&self) -> Vec<u64> {
+        self.numbers
+            .iter()
+            .filter(|n| {
+                self.adjacent_positions(n)
+                    .iter()
+                    .any(|p| self.symbols.contains(p))
+            })
+            .map(|n| n.3)
+            .collect()
+    }
+
+    pub fn get_gear_ratios(&self) -> Vec<u64> {
+        // Lookup table of * symbol position -> adjacent numbers
+        let mut adjacent_numbers: HashMap<(usize, usize), Vec<u64>> = HashMap::new();
+        for n in self.numbers.iter() {
+            for p in self.adjacent_positions(n).iter() {
+                if self.data[p.0].chars().nth(p.1) == Some('*') {
+                    adjacent_numbers.entry(*p).or_default().push(n.3);
+                }
+            }
+        }
+        // Find all the * with exactly two numbers adjacent
+        adjacent_numbers
+            .iter()
+            .filter(|(_p, n)| n.len() == 2)
+            .map(|(_p, n)| n[0] * n[1])
+            .collect()
+    }
+
+    fn adjacent_positions(&self, number_tuple: &(usize, usize, usize, u64)) -> Vec<(usize, usize)> {
+        let (row, col, len, _value) = *number_tuple;
+        let mut result = Vec::new();
+
+        // Positions before the number
+        if col > 0 {
+            if row > 0 {
+                result.push((row - 1, col - 1));
+            }
+            result.push((row, col - 1));
+            if row + 1 < self.data.len() {
+                result.push((row + 1, col - 1));
+            }
+        }
+
+        // Positions above and below the number
+        for c in col..col + len {
+            if row > 0 {
+                result.push((row - 1, c));
+            }
+            if row + 1 < self.data.len() {
+                result.push((row + 1, c));
+            }
+        }
+
+        // Positions after the number
+        let col_after = col + len;
+        if col_after < self.data[0].len() {
+            if row > 0 {
+                result.push((row - 1, col_after));
+            }
+            result.push((row, col_after));
+            if row + 1 < self.data.len() {
+                result.push((row + 1, col_after));
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::io;
+    use lazy_static::lazy_static;
+
+    lazy_static! {
+        static ref EX_IN: Vec<String> =
+            io::read_file_as_lines("inputs/2023/examples/day03-ex1.txt").unwrap();
+    }
+
+    #[test]
+    fn test_sum_of_part_numbers() {
+        let part_numbers = EngineSchematic::new(&EX_IN).unwrap().get_part_numbers();
+        assert_eq!(4361u64, part_numbers.iter().sum::<u64>());
+    }
+
+    #[test]
+    fn test_sum_of_gear_ratios() {
+        let gear_ratios = EngineSchematic::new(&EX_IN).unwrap().get_gear_ratios();
+        assert_eq!(467835u64, gear_ratios.iter().sum::<u64>());
+    }
+
+    #[test]
+    fn test_number_too_large() {
+        let lines = vec!["..1..".to_string(), "*99999999999999999999".to_string()];
+        let error = EngineSchematic::new(&lines).err().unwrap();
+        assert_eq!(2, error.location().line);
+        assert_eq!((2, 20), (error.location().column, error.location().width));
+    }
+
+    #[test]
+    fn test_check_line_widths() {
+        let lines = ["467..", "...*...", "35", ".633."].map(String::from);
+        let problems = Solver::default().check(&lines);
+        let found = problems
+            .iter()
+            .map(|p| {
+                let l = p.location();
+                (l.line, l.column, l.width)
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(vec![(2, 6, 2), (3, 1, 2)], found);
+    }
+}
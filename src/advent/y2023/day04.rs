@@ -0,0 +1,234 @@
+use crate::advent::{AdventSolver, Answer};
+use crate::error::{parse_number, InputError};
+use anyhow::Error;
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::collections::HashSet;
+
+pub const DAY: u32 = 4;
+pub const TITLE: &str = "Scratchcards";
+
+#[derive(Default)]
+pub struct Solver {
+    cards: Vec<Card>,
+}
+
+lazy_static! {
+    static ref CARD_RE: Regex =
+        Regex::new(r"^Card +(?P<id>\d+): (?P<winners>[0-9 ]+) \| (?P<picks>[0-9 ]+)$").unwrap();
+    static ref CARD_HEADER_RE: Regex = Regex::new(r"^Card +(?P<id>\d+): ").unwrap();
+    static ref NUMBER_RE: Regex = Regex::new(r"\d+").unwrap();
+}
+
+impl AdventSolver for Solver {
+    fn parse(&mut self, input: &[String]) -> Result<(), Error> {
+        self.cards = input
+            .iter()
+            .enumerate()
+            .map(|(i, line)| Card::parse(line).map_err(|e| e.on_line(i)))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(())
+    }
+
+    fn check(&mut self, input: &[String]) -> Vec<InputError> {
+        let mut problems = Vec::new();
+        for (i, line) in input.iter().enumerate() {
+            if let Err(e) = Card::parse(line) {
+                problems.push(e.on_line(i));
+            }
+            // Copies are won by position, so each card's ID has to match its place in the table
+            if let Some(id) = CARD_HEADER_RE
+                .captures(line)
+                .and_then(|caps| caps.name("id"))
+            {
+                if id.as_str().parse::<usize>() != Ok(i + 1) {
+                    let message = format!("Card out of sequence, expected card {}", i + 1);
+                    problems.push(InputError::invalid_at(message, line, id.range()).on_line(i));
+                }
+            }
+        }
+        problems
+    }
+
+    fn part_one(&self) -> Result<Answer, Error> {
+        Ok(Answer::new(
+            "Sum of card point values",
+            self.cards.iter().map(|c| c.point_value()).sum::<u64>(),
+        ))
+    }
+
+    fn part_two(&self) -> Result<Answer, Error> {
+        let mut cards = self.cards.clone();
+        Self::propagate_wins(&mut cards);
+        Ok(Answer::new(
+            "Card count after propagation",
+            cards.iter().map(|c| c.copies).sum::<u64>(),
+        ))
+    }
+}
+
+impl Solver {
+    pub fn propagate_wins(cards: &mut [Card]) {
+        for i in 0..cards.len() {
+            // Cards never make you copy past the end of the table
+            let last = (i + cards[i].win_count() as usize).min(cards.len() - 1);
+            for j in i + 1..=last {
+                cards[j].copies += cards[i].copies;
+            }
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Card {
+    pub copies: u64,
+    winners: HashSet<u64>,
+    picks: HashSet<u64>,
+}
+
+impl Card {
+    pub fn parse(line: &str) -> Result<Card, InputError> {
+        if let Some(caps) = CARD_RE.captures(line) {
+            let winners = Self::parse_numbers(line, caps.name("winners").unwrap())?;
+            let picks = Self::parse_numbers(line, caps.name("picks").unwrap())?;
+            Ok(Card {
+                copies: 1,
+                winners,
+                picks,
+            })
+        } else {
+            // Point at the first character that can't belong to a card's numbers, if any
+            let stray = CARD_HEADER_RE.find(line).and_then(|header| {
+                line[header.end()..]
+                    .find(|c: char| !c.is_ascii_digit() && c != ' ' && c != '|')
+                    .map(|i| header.end() + i)
+            });
+            match stray {
+                Some(i) => Err(InputError::malformed_at(
+                    "Could not parse card",
+                    line,
+                    i..i + 1,
+                )),
+                None => Err(InputError::malformed("Could not parse card", line)),
+            }
+        }
+    }
+
+    fn parse_numbers(line: &str, numbers: regex::Match) -> Result<HashSet<u64>, InputError> {
+        NUMBER_RE
+            .find_iter(numbers.as_str())
+            .map(|m| parse_number(line, numbers.start() + m.start()..numbers.start() + m.end()))
+            .collect()
+    }
+
+    pub fn point_value(&self) -> u64 {
+        let count = self.win_count();
+        if count == 0 {
+            0
+        } else {
+            2u64.pow((count - 1) as u32)
+        }
+    }
+
+    pub fn win_count(&self) -> u64 {
+        self.winners
+            .iter()
+            .filter(|w| self.picks.contains(w))
+            .count() as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Card, Solver, DAY};
+    use crate::advent::AdventSolver;
+    use crate::error::InputError;
+    use crate::generate;
+    use crate::util::io;
+    use lazy_static::lazy_static;
+
+    lazy_static! {
+        static ref EX_IN: Vec<String> =
+            io::read_file_as_lines("inputs/2023/examples/day04-ex1.txt").unwrap();
+    }
+
+    #[test]
+    fn test_example_cards() {
+        let cards = EX_IN
+            .iter()
+            .map(|line| Card::parse(line).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(8, cards[0].point_value());
+        assert_eq!(2, cards[1].point_value());
+        assert_eq!(2, cards[2].point_value());
+        assert_eq!(1, cards[3].point_value());
+        assert_eq!(0, cards[4].point_value());
+        assert_eq!(0, cards[5].point_value());
+    }
+
+    #[test]
+    fn test_part2_copy_rule() {
+        let mut cards = EX_IN
+            .iter()
+            .map(|line| Card::parse(line).unwrap())
+            .collect::<Vec<_>>();
+
+        Solver::propagate_wins(&mut cards);
+        assert_eq!(30u64, cards.iter().map(|c| c.copies).sum::<u64>());
+    }
+
+    #[test]
+    fn test_malformed_card() {
+        let error = Card::parse("Card 3:  1 21 x3 59 | 69 82").err().unwrap();
+        assert!(matches!(error, InputError::Malformed { .. }));
+        assert_eq!((15, 1), (error.location().column, error.location().width));
+
+        let error = Card::parse("Card 3: 1 | 2 99999999999999999999")
+            .err()
+            .unwrap();
+        assert!(matches!(error, InputError::InvalidValue { .. }));
+        assert_eq!((15, 20), (error.location().column, error.location().width));
+    }
+
+    #[test]
+    fn test_wins_past_the_last_card() {
+        let mut cards = vec![Card::parse("Card 1: 1 2 | 1 2").unwrap()];
+        Solver::propagate_wins(&mut cards);
+        assert_eq!(1, cards[0].copies);
+    }
+
+    #[test]
+    fn test_check_card_sequence() {
+        let lines = ["Card 1: 1 | 1", "Card 3: 1 | 2", "Card 3: 1 | x"].map(String::from);
+        let problems = Solver::default().check(&lines);
+        let found = problems
+            .iter()
+            .map(|p| (p.location().line, p.location().column))
+            .collect::<Vec<_>>();
+        assert_eq!(vec![(2, 6), (3, 13)], found);
+    }
+
+    // Counts the cards won from card `i` by following every copy, one at a time.
+    fn brute_force_card_count(cards: &[Card], i: usize) -> u64 {
+        let last = (i + cards[i].win_count() as usize).min(cards.len() - 1);
+        1 + (i + 1..=last)
+            .map(|j| brute_force_card_count(cards, j))
+            .sum::<u64>()
+    }
+
+    #[test]
+    fn test_propagation_matches_brute_force() {
+        for seed in 0..20 {
+            let mut cards = generate::input(2023, DAY, 30, seed)
+                .unwrap()
+                .iter()
+                .map(|line| Card::parse(line).unwrap())
+                .collect::<Vec<_>>();
+            let expected = (0..cards.len())
+                .map(|i| brute_force_card_count(&cards, i))
+                .sum::<u64>();
+            Solver::propagate_wins(&mut cards);
+            assert_eq!(expected, cards.iter().map(|c| c.copies).sum::<u64>());
+        }
+    }
+}
@@ -0,0 +1,368 @@
+use crate::advent::{AdventSolver, Answer};
+use crate::cancel;
+use crate::error::{parse_number, InputError};
+use crate::progress::Progress;
+use anyhow::{format_err, Error};
+use itertools::Itertools;
+use lazy_static::lazy_static;
+use log::debug;
+use regex::Regex;
+use std::cmp::min;
+use std::collections::HashMap;
+use std::ops::Range;
+use strum::{self, Display, EnumString};
+
+pub const DAY: u32 = 5;
+pub const TITLE: &str = "If You Give A Seed A Fertilizer";
+
+#[derive(Default)]
+pub struct Solver {
+    almanac: Almanac,
+}
+
+impl AdventSolver for Solver {
+    fn parse(&mut self, input: &[String]) -> Result<(), Error> {
+        self.almanac = Almanac::new(input)?;
+        Ok(())
+    }
+
+    fn check(&mut self, input: &[String]) -> Vec<InputError> {
+        Almanac::check(input)
+    }
+
+    fn part_one(&self) -> Result<Answer, Error> {
+        let almanac = &self.almanac;
+        Ok(Answer::new(
+            "Lowest location number",
+            almanac
+                .seeds_to_plant
+                .iter()
+                .map(|&s| almanac.location_for_seed(s))
+                .min()
+                .ok_or(format_err!("No seeds to plant"))?,
+        ))
+    }
+
+    fn part_two(&self) -> Result<Answer, Error> {
+        let almanac = &self.almanac;
+        let mut lowest_location = u64::MAX;
+        let seed_count = almanac
+            .seeds_to_plant
+            .iter()
+            .tuples()
+            .fold(0u64, |count, (_, &length)| count.saturating_add(length));
+        let mut progress = Progress::new("Seeds", Some(seed_count));
+        for (&seed_start, &length) in almanac.seeds_to_plant.iter().tuples() {
+            let seed_end = seed_start.saturating_add(length);
+            let mut seed = seed_start;
+            while seed < seed_end {
+                cancel::checkpoint()?;
+                let (location, step) = almanac.optimized_location_for_seed(seed);
+                if location < lowest_location {
+                    lowest_location = location;
+                }
+                progress.advance(step.min(seed_end - seed));
+                seed = seed.saturating_add(step);
+            }
+        }
+        Ok(Answer::new(
+            "Considering seed ranges, lowest location number",
+            lowest_location,
+        ))
+    }
+}
+
+#[derive(Clone, Copy, Debug, Display, EnumString, Eq, Hash, PartialEq)]
+#[strum(serialize_all = "lowercase")]
+pub enum GardenResource {
+    Fertilizer,
+    Humidity,
+    Light,
+    Location,
+    Seed,
+    Soil,
+    Temperature,
+    Water,
+}
+
+lazy_static! {
+    static ref SEEDS_RE: Regex = Regex::new(r"^seeds: (?P<seeds>[0-9 ]+)$").unwrap();
+    static ref NUMBER_RE: Regex = Regex::new(r"\d+").unwrap();
+    static ref MAP_START_RE: Regex =
+        Regex::new(r"^(?P<source>[a-z]+)-to-(?P<dest>[a-z]+) map:").unwrap();
+    static ref MAP_ROW_RE: Regex =
+        Regex::new(r"^(?P<dest>\d+) (?P<source>\d+) (?P<len>\d+)$").unwrap();
+    static ref CONVERSIONS: Vec<GardenResource> = vec![
+        GardenResource::Seed,
+        GardenResource::Soil,
+        GardenResource::Fertilizer,
+        GardenResource::Water,
+        GardenResource::Light,
+        GardenResource::Temperature,
+        GardenResource::Humidity,
+        GardenResource::Location,
+    ];
+}
+
+/// Source ranges paired with the start of the destination range they map to, keyed by the
+/// (source, dest) resources being converted.
+type ResourceMaps = HashMap<(GardenResource, GardenResource), Vec<(Range<u64>, u64)>>;
+
+#[derive(Default)]
+pub struct Almanac {
+    pub seeds_to_plant: Vec<u64>,
+    maps: ResourceMaps,
+}
+
+impl Almanac {
+    pub fn new<S: AsRef<str>>(input: &[S]) -> Result<Almanac, InputError> {
+        let (almanac, problems) = Self::read(input, false);
+        match problems.into_iter().next() {
+            Some(problem) => Err(problem),
+            None => Ok(almanac),
+        }
+    }
+
+    /// Finds every problem in the input, including overlapping ranges within a map (which `new`
+    /// allows; the first matching range wins).
+    pub fn check<S: AsRef<str>>(input: &[S]) -> Vec<InputError> {
+        Self::read(input, true).1
+    }
+
+    // Reads as much of the almanac as possible, carrying on past bad lines.
+    fn read<S: AsRef<str>>(input: &[S], check_overlaps: bool) -> (Almanac, Vec<InputError>) {
+        // Almanac fields
+        let mut seeds_to_plant = Vec::new();
+        let mut maps: ResourceMaps = HashMap::new();
+        let mut problems = Vec::new();
+        // When we're reading resource maps (e.g. "seed-to-soil") this will be populated, with None
+        // if the map's header was bad (so its rows are skipped rather than each reported).
+        let mut current_map: Option<Option<(GardenResource, GardenResource)>> = None;
+        // The line each map row was read from, for reporting overlaps
+        let mut row_lines: HashMap<(GardenResource, GardenResource), Vec<usize>> = HashMap::new();
+
+        for (i, line) in input.iter().enumerate() {
+            let line = line.as_ref();
+            let mut read_line = || -> Result<(), InputError> {
+                if line.is_empty() {
+                    // Blank line signals a change to a new map
+                    current_map = None;
+                } else if let Some(caps) = SEEDS_RE.captures(line) {
+                    // The "seeds to plant" line.
+                    let seeds = caps.name("seeds").unwrap();
+                    seeds_to_plant = NUMBER_RE
+                        .find_iter(seeds.as_str())
+                        .map(|m| {
+                            parse_number(line, seeds.start() + m.start()..seeds.start() + m.end())
+                        })
+                        .collect::<Result<Vec<_>, _>>()?;
+                } else if let Some(caps) = MAP_START_RE.captures(line) {
+                    // A new source-to-dest map is starting
+                    // (Marked bad until both resources are known to be good)
+                    current_map = Some(None);
+                    let resource = |name| {
+                        let m = caps.name(name).unwrap();
+                        GardenResource::try_from(m.as_str()).map_err(|_| {
+                            InputError::invalid_at("Unknown resource", line, m.range())
+                        })
+                    };
+                    current_map = Some(Some((resource("source")?, resource("dest")?)));
+                } else if let Some(caps) = MAP_ROW_RE.captures(line) {
+                    // Read a single line of a source-to-dest map
+                    let key = match current_map {
+                        Some(Some(key)) => key,
+                        Some(None) => return Ok(()),
+                        None => {
+                            return Err(InputError::malformed("Map row without a map header", line))
+                        }
+                    };
+                    let number = |name| parse_number::<u64>(line, caps.name(name).unwrap().range());
+                    let s_start = number("source")?;
+                    let d_start = number("dest")?;
+                    let len = number("len")?;
+                    let span = caps.name("source").unwrap().start()..line.len();
+                    if s_start.checked_add(len).is_none() || d_start.checked_add(len).is_none() {
+                        return Err(InputError::invalid_at("Range is too long", line, span));
+                    }
+                    let srange = s_start..s_start + len;
+                    let rows = maps.entry(key).or_default();
+                    let lines = row_lines.entry(key).or_default();
+                    if check_overlaps {
+                        let overlap = rows.iter().zip(lines.iter()).find(|((other, _), _)| {
+                            srange.start < other.end && other.start < srange.end
+                        });
+                        if let Some((_, other_line)) = overlap {
+                            let message =
+                                format!("Source range overlaps the one on line {}", other_line + 1);
+                            return Err(InputError::invalid_at(message, line, span));
+                        }
+                    }
+                    rows.push((srange, d_start));
+                    lines.push(i);
+                } else {
+                    return Err(InputError::malformed("Unexpected line in input", line));
+                }
+                Ok(())
+            };
+            if let Err(e) = read_line() {
+                problems.push(e.on_line(i));
+            }
+        }
+        (
+            Almanac {
+                seeds_to_plant,
+                maps,
+            },
+            problems,
+        )
+    }
+
+    pub fn convert_resource(
+        &self,
+        source: GardenResource,
+        dest: GardenResource,
+        value: u64,
+    ) -> u64 {
+        if let Some(ranges) = self.maps.get(&(source, dest)) {
+            for (srange, dstart) in ranges.iter() {
+                if srange.contains(&value) {
+                    let offset = value - srange.start;
+                    return dstart + offset;
+                }
+            }
+        }
+        value
+    }
+
+    // Implements the entire lookup chain described in part 1, assuming it is static.
+    pub fn location_for_seed(&self, seed: u64) -> u64 {
+        CONVERSIONS
+            .iter()
+            .tuple_windows()
+            .fold(seed, |resource, (&source, &dest)| {
+                let converted = self.convert_resource(source, dest, resource);
+                debug!("{} {} -> {} {}", source, resource, dest, converted);
+                converted
+            })
+    }
+
+    // Returns the location and a suggested number of seeds to skip for the next attempt, based on
+    // how far the next breakpoint is in the mappings. (The only points where the location could
+    // possibly get lower while the seed number is increasing.)
+    pub fn optimized_location_for_seed(&self, seed: u64) -> (u64, u64) {
+        let mut step: u64 = u64::MAX;
+        let mut value = seed;
+        CONVERSIONS
+            .iter()
+            .tuple_windows()
+            .for_each(|(&source, &dest)| {
+                let range_maps = self.maps.get(&(source, dest)).map_or(&[][..], |m| m);
+                for (srange, dstart) in range_maps.iter() {
+                    if srange.contains(&value) {
+                        let offset = value - srange.start;
+                        step = min(step, srange.end - value);
+                        value = dstart + offset;
+                        break;
+                    }
+                }
+                // If we're not inside a mapping range (in the fallthrough 1:1 behavior), we need
+                // to consider where the next mapping begins.
+                for (srange, _dstart) in range_maps.iter() {
+                    if srange.start > value {
+                        step = min(step, srange.start - value);
+                    }
+                }
+            });
+        (value, step)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Almanac, Solver, DAY};
+    use crate::advent::AdventSolver;
+    use crate::error::InputError;
+    use crate::generate;
+    use crate::util::io;
+    use itertools::Itertools;
+    use lazy_static::lazy_static;
+
+    lazy_static! {
+        static ref EX_IN: Vec<String> =
+            io::read_file_as_lines("inputs/2023/examples/day05-ex1.txt").unwrap();
+    }
+
+    #[test]
+    fn test_lookup_seed_location() {
+        let almanac = Almanac::new(&EX_IN).unwrap();
+        assert_eq!(82, almanac.location_for_seed(79));
+        assert_eq!(43, almanac.location_for_seed(14));
+        assert_eq!(86, almanac.location_for_seed(55));
+        assert_eq!(35, almanac.location_for_seed(13));
+    }
+
+    #[test]
+    fn test_malformed_almanac() {
+        let error = Almanac::new(&["seeds: 1 2", "", "seed-to-dirt map:"])
+            .err()
+            .unwrap();
+        assert!(matches!(error, InputError::InvalidValue { .. }));
+        let l = error.location();
+        assert_eq!((3, 9, 4), (l.line, l.column, l.width));
+
+        let error = Almanac::new(&["seeds: 1 2", "", "50 98 2"]).err().unwrap();
+        assert_eq!("Map row without a map header", error.message());
+        assert_eq!(3, error.location().line);
+    }
+
+    #[test]
+    fn test_check_overlapping_ranges() {
+        let mut input = EX_IN.clone();
+        // Overlaps "50 98 2" in the seed-to-soil map, but not the ranges in other maps
+        input.insert(4, "10 90 9".to_string());
+        input.insert(5, "seed-to-dirt map:".to_string());
+        let problems = Almanac::check(&input);
+        let found = problems
+            .iter()
+            .map(|p| (p.message(), p.location().line))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                ("Source range overlaps the one on line 4", 5),
+                ("Unknown resource", 6),
+            ],
+            found
+        );
+        assert!(Almanac::check(&EX_IN).is_empty());
+        assert!(Almanac::new(&input[..5]).is_ok());
+    }
+
+    #[test]
+    fn test_optimized_walk_matches_brute_force() {
+        for seed in 0..50 {
+            let almanac = Almanac::new(&generate::input(2023, DAY, 4, seed).unwrap()).unwrap();
+            let mut lowest = u64::MAX;
+            for (&start, &length) in almanac.seeds_to_plant.iter().tuples() {
+                let mut seed = start;
+                while seed < start + length {
+                    let (location, step) = almanac.optimized_location_for_seed(seed);
+                    // Every seed skipped over must be one further along than the one before
+                    for skipped in 0..step.min(start + length - seed) {
+                        assert_eq!(
+                            location + skipped,
+                            almanac.location_for_seed(seed + skipped)
+                        );
+                    }
+                    seed = seed.saturating_add(step);
+                }
+                let brute_force = (start..start + length)
+                    .map(|seed| almanac.location_for_seed(seed))
+                    .min()
+                    .unwrap();
+                lowest = lowest.min(brute_force);
+            }
+            let solver = Solver { almanac };
+            assert_eq!(lowest.to_string(), solver.part_two().unwrap().value);
+        }
+    }
+}
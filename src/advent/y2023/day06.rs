@@ -0,0 +1,176 @@
+use crate::advent::{AdventSolver, Answer};
+use crate::error::{parse_number, InputError};
+use crate::progress::Progress;
+use anyhow::Error;
+use lazy_static::lazy_static;
+use regex::Regex;
+
+pub const DAY: u32 = 6;
+pub const TITLE: &str = "Wait For It";
+
+#[derive(Default)]
+pub struct Solver {
+    times: Vec<u64>,
+    distances: Vec<u64>,
+    time: u64,
+    distance: u64,
+}
+
+impl AdventSolver for Solver {
+    fn parse(&mut self, input: &[String]) -> Result<(), Error> {
+        (self.times, self.time) = parse_row(input, 0, "Time")?;
+        (self.distances, self.distance) = parse_row(input, 1, "Distance")?;
+        Ok(())
+    }
+
+    fn check(&mut self, input: &[String]) -> Vec<InputError> {
+        let times = parse_row(input, 0, "Time");
+        let distances = parse_row(input, 1, "Distance");
+        let mut problems = Vec::new();
+        match (times, distances) {
+            (Ok((times, _)), Ok((distances, _))) if times.len() != distances.len() => {
+                let message = format!("{} distances for {} times", distances.len(), times.len());
+                let line = &input[1];
+                let span = line.find(' ').unwrap_or(0)..line.len();
+                problems.push(InputError::invalid_at(message, line, span).on_line(1));
+            }
+            (times, distances) => problems.extend(times.err().into_iter().chain(distances.err())),
+        }
+        for (i, line) in input.iter().enumerate().skip(2) {
+            if !line.is_empty() {
+                problems.push(InputError::malformed("Unexpected line in input", line).on_line(i));
+            }
+        }
+        problems
+    }
+
+    fn part_one(&self) -> Result<Answer, Error> {
+        Ok(Answer::new(
+            "Ways to win",
+            ways_to_beat_records(&self.times, &self.distances),
+        ))
+    }
+
+    fn part_two(&self) -> Result<Answer, Error> {
+        Ok(Answer::new(
+            "Ways to win",
+            ways_to_beat_record(self.time, self.distance),
+        ))
+    }
+}
+
+lazy_static! {
+    static ref NUMBER_RE: Regex = Regex::new(r"\d+").unwrap();
+}
+
+/// Parses the `label` row at `index`, returning its numbers along with the "bad kerning" version
+/// that reads all of its digits as one number.
+fn parse_row(input: &[String], index: usize, label: &str) -> Result<(Vec<u64>, u64), InputError> {
+    let Some(line) = input.get(index) else {
+        return Err(InputError::missing(format!("Missing {} row", label), "").on_line(index));
+    };
+    let on_line = |e: InputError| e.on_line(index);
+    let prefix = format!("{}:", label);
+    if !line.starts_with(&prefix) {
+        return Err(on_line(InputError::malformed(
+            format!("Expected {} row", label),
+            line,
+        )));
+    }
+    if let Some(i) = line[prefix.len()..].find(|c: char| !c.is_ascii_digit() && c != ' ') {
+        let i = prefix.len() + i;
+        return Err(on_line(InputError::malformed_at(
+            "Not a number",
+            line,
+            i..i + 1,
+        )));
+    }
+    let numbers = NUMBER_RE
+        .find_iter(line)
+        .map(|m| parse_number(line, m.range()))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(on_line)?;
+    if numbers.is_empty() {
+        return Err(on_line(InputError::missing(
+            format!("No numbers in {} row", label),
+            line,
+        )));
+    }
+    let digits: String = line.chars().filter(|c| c.is_ascii_digit()).collect();
+    let kerned = digits.parse::<u64>().map_err(|e| {
+        let span = prefix.len()..line.trim_end().len();
+        on_line(InputError::invalid_at(
+            format!("Invalid number ({})", e),
+            line,
+            span,
+        ))
+    })?;
+    Ok((numbers, kerned))
+}
+
+fn hold_button_and_go(hold_time: u64, race_time: u64) -> u64 {
+    hold_time * (race_time - hold_time)
+}
+
+pub fn ways_to_beat_record(time: u64, distance_record: u64) -> u64 {
+    let mut progress = Progress::new("Hold times", Some(time.saturating_sub(1)));
+    (1..time)
+        .inspect(|_| progress.advance(1))
+        .map(|h| hold_button_and_go(h, time))
+        .filter(|&d| d > distance_record)
+        .count() as u64
+}
+
+pub fn ways_to_beat_records(times: &[u64], distances: &[u64]) -> u64 {
+    times
+        .iter()
+        .zip(distances.iter())
+        .fold(1, |ways, (&time, &distance)| {
+            ways * ways_to_beat_record(time, distance)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        assert_eq!(288, ways_to_beat_records(&[7, 15, 30], &[9, 40, 200]));
+    }
+
+    #[test]
+    fn test_example_2() {
+        assert_eq!(71503, ways_to_beat_record(71530, 940200));
+    }
+
+    #[test]
+    fn test_malformed_rows() {
+        let input = vec!["Time:      7  15   30".to_string()];
+        let error = parse_row(&input, 1, "Distance").err().unwrap();
+        assert!(matches!(error, InputError::Missing { .. }));
+        assert_eq!(2, error.location().line);
+
+        let input = vec!["Time:      7  1x   30".to_string()];
+        let error = parse_row(&input, 0, "Time").err().unwrap();
+        assert_eq!((16, 1), (error.location().column, error.location().width));
+
+        let input = vec!["Time:      7  15   30".to_string()];
+        assert_eq!(
+            (vec![7, 15, 30], 71530),
+            parse_row(&input, 0, "Time").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_check_counts() {
+        let input = ["Time:      7  15   30", "Distance:  9  40"].map(String::from);
+        let problems = Solver::default().check(&input);
+        assert_eq!(1, problems.len());
+        assert_eq!("2 distances for 3 times", problems[0].message());
+        assert_eq!(
+            (2, 10),
+            (problems[0].location().line, problems[0].location().column)
+        );
+    }
+}
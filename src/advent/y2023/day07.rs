@@ -0,0 +1,383 @@
+use crate::advent::{AdventSolver, Answer};
+use crate::error::{parse_number, InputError};
+use anyhow::Error;
+use log::debug;
+use std::cmp::{Ord, Ordering, PartialEq, PartialOrd};
+use std::fmt;
+use std::ops::Range;
+use std::str::FromStr;
+
+pub const DAY: u32 = 7;
+pub const TITLE: &str = "Camel Cards";
+
+#[derive(Default)]
+pub struct Solver {
+    hands: Vec<(CamelHand, u64)>,
+}
+
+impl AdventSolver for Solver {
+    fn parse(&mut self, input: &[String]) -> Result<(), Error> {
+        self.hands = input
+            .iter()
+            .enumerate()
+            .map(|(i, line)| Self::parse_line(line).map_err(|e| e.on_line(i)))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(())
+    }
+
+    fn check(&mut self, input: &[String]) -> Vec<InputError> {
+        input
+            .iter()
+            .enumerate()
+            .filter_map(|(i, line)| Self::parse_line(line).err().map(|e| e.on_line(i)))
+            .collect()
+    }
+
+    fn part_one(&self) -> Result<Answer, Error> {
+        Ok(Answer::new(
+            "Total winnings",
+            Self::total_winnings(&self.hands),
+        ))
+    }
+
+    fn part_two(&self) -> Result<Answer, Error> {
+        let mut hands = self.hands.clone();
+        for (ref mut hand, _) in hands.iter_mut() {
+            hand.jokers_wild();
+        }
+        Ok(Answer::new("Total winnings", Self::total_winnings(&hands)))
+    }
+}
+
+impl Solver {
+    // A hand and its bet, e.g. "32T3K 765"
+    fn parse_line(line: &str) -> Result<(CamelHand, u64), InputError> {
+        let Some((hand, bet)) = line.split_once(' ') else {
+            return Err(InputError::malformed("Expected a hand and a bet", line));
+        };
+        let hand = CamelHand::parse_at(line, 0..hand.len())?;
+        let bet = parse_number(line, line.len() - bet.len()..line.len())?;
+        Ok((hand, bet))
+    }
+
+    pub fn total_winnings(hands: &[(CamelHand, u64)]) -> u64 {
+        let mut sorted: Vec<(CamelHand, u64)> = hands.to_vec();
+        sorted.sort();
+        sorted
+            .iter()
+            .enumerate()
+            .fold(0, |total, (i, (_hand, bet))| total + bet * (i as u64 + 1))
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum CamelHandType {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CamelHand {
+    cards: Vec<char>,
+    jokers: bool,
+    // Worked out once up front, since sorting compares hands over and over
+    hand_type: CamelHandType,
+}
+
+/// Every card, from strongest to weakest.
+pub const CARDS: &str = "AKQJT98765432";
+
+impl CamelHand {
+    // Parses the hand at byte offsets `span` of `line`, so that errors point into the line.
+    fn parse_at(line: &str, span: Range<usize>) -> Result<CamelHand, InputError> {
+        let hand = &line[span.clone()];
+        if let Some((i, c)) = hand.char_indices().find(|&(_, c)| !CARDS.contains(c)) {
+            let start = span.start + i;
+            return Err(InputError::malformed_at(
+                "Not a card",
+                line,
+                start..start + c.len_utf8(),
+            ));
+        }
+        if hand.len() != 5 {
+            return Err(InputError::malformed_at(
+                "A hand must have five cards",
+                line,
+                span,
+            ));
+        }
+        let cards = hand.chars().collect::<Vec<_>>();
+        Ok(CamelHand {
+            hand_type: Self::base_hand_type(&cards),
+            cards,
+            jokers: false,
+        })
+    }
+
+    /// The type of the hand, counting jokers as wild once `jokers_wild` has been called.
+    pub fn hand_type(&self) -> CamelHandType {
+        self.hand_type
+    }
+
+    // Joker-enabled hand type calculation
+    fn wild_hand_type(&self) -> CamelHandType {
+        let joker_count = self.cards.iter().filter(|&&c| c == 'J').count();
+        if !self.jokers || joker_count == 0 || joker_count == 5 {
+            Self::base_hand_type(&self.cards)
+        } else {
+            // Hand contains 1 to 4 jokers and jokers are wild.
+            let normal_cards = self
+                .cards
+                .iter()
+                .copied()
+                .filter(|&c| c != 'J')
+                .collect::<Vec<_>>();
+            // Try replacing jokers with a copy of each of the other cards in the hand, and see
+            // which results in the best hand value.
+            let (card, hand_type) = normal_cards
+                .iter()
+                .map(|&c| {
+                    let wild_hand = [normal_cards.clone(), [c].repeat(joker_count)].concat();
+                    (c, Self::base_hand_type(&wild_hand))
+                })
+                .max_by(|a, b| a.1.cmp(&b.1))
+                .unwrap();
+            debug!("{}: jokers as {} make {:?}", self, card, hand_type);
+            hand_type
+        }
+    }
+
+    // Non-joker-enabled hand type calculation
+    fn base_hand_type(cards: &[char]) -> CamelHandType {
+        let mut sorted = cards.to_vec();
+        sorted.sort();
+        let at_least_three_of_a_kind =
+            sorted[0] == sorted[2] || sorted[1] == sorted[3] || sorted[2] == sorted[4];
+        let at_least_four_of_a_kind = sorted[0] == sorted[3] || sorted[1] == sorted[4];
+        let mut deduped = sorted.to_vec();
+        deduped.dedup();
+        match deduped.len() {
+            5 => CamelHandType::HighCard,
+            4 => CamelHandType::OnePair,
+            3 => {
+                if at_least_three_of_a_kind {
+                    CamelHandType::ThreeOfAKind
+                } else {
+                    CamelHandType::TwoPair
+                }
+            }
+            2 => {
+                if at_least_four_of_a_kind {
+                    CamelHandType::FourOfAKind
+                } else {
+                    CamelHandType::FullHouse
+                }
+            }
+            1 => CamelHandType::FiveOfAKind,
+            _ => unreachable!("hands are checked for five cards when parsed"),
+        }
+    }
+
+    fn card_value(&self, card: char) -> u64 {
+        match card {
+            'A' => 14,
+            'K' => 13,
+            'Q' => 12,
+            'J' => {
+                if self.jokers {
+                    1
+                } else {
+                    11
+                }
+            }
+            'T' => 10,
+            '9' => 9,
+            '8' => 8,
+            '7' => 7,
+            '6' => 6,
+            '5' => 5,
+            '4' => 4,
+            '3' => 3,
+            '2' => 2,
+            _ => unreachable!("cards are checked when parsed"),
+        }
+    }
+
+    pub fn jokers_wild(&mut self) {
+        self.jokers = true;
+        self.hand_type = self.wild_hand_type();
+    }
+}
+
+/// Parses a hand of five cards, e.g. "32T3K".
+impl FromStr for CamelHand {
+    type Err = InputError;
+
+    fn from_str(s: &str) -> Result<CamelHand, InputError> {
+        Self::parse_at(s, 0..s.len())
+    }
+}
+
+impl fmt::Display for CamelHand {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.cards.iter().collect::<String>())
+    }
+}
+
+impl Ord for CamelHand {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (
+            self.hand_type(),
+            self.card_value(self.cards[0]),
+            self.card_value(self.cards[1]),
+            self.card_value(self.cards[2]),
+            self.card_value(self.cards[3]),
+            self.card_value(self.cards[4]),
+        )
+            .cmp(&(
+                other.hand_type(),
+                self.card_value(other.cards[0]),
+                self.card_value(other.cards[1]),
+                self.card_value(other.cards[2]),
+                self.card_value(other.cards[3]),
+                self.card_value(other.cards[4]),
+            ))
+    }
+}
+
+impl PartialOrd for CamelHand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate;
+
+    fn hand(s: &str) -> CamelHand {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_example_hand_winnings() {
+        let hands = vec![
+            (hand("32T3K"), 765),
+            (hand("T55J5"), 684),
+            (hand("KK677"), 28),
+            (hand("KTJJT"), 220),
+            (hand("QQQJA"), 483),
+        ];
+        assert_eq!(6440, Solver::total_winnings(&hands));
+    }
+
+    #[test]
+    fn test_example_hand_sorting() {
+        let mut hands = [
+            hand("32T3K"),
+            hand("T55J5"),
+            hand("KK677"),
+            hand("KTJJT"),
+            hand("QQQJA"),
+        ];
+        hands.sort();
+        assert_eq!("32T3K".to_string(), hands[0].to_string());
+        assert_eq!("KTJJT".to_string(), hands[1].to_string());
+        assert_eq!("KK677".to_string(), hands[2].to_string());
+        assert_eq!("T55J5".to_string(), hands[3].to_string());
+        assert_eq!("QQQJA".to_string(), hands[4].to_string());
+    }
+
+    #[test]
+    fn test_example_hand_sorting_with_jokers() {
+        let mut hands = [
+            hand("32T3K"),
+            hand("T55J5"),
+            hand("KK677"),
+            hand("KTJJT"),
+            hand("QQQJA"),
+        ];
+        hands.iter_mut().for_each(|h| h.jokers_wild());
+        hands.sort();
+        assert_eq!("32T3K".to_string(), hands[0].to_string());
+        assert_eq!("KK677".to_string(), hands[1].to_string());
+        assert_eq!("T55J5".to_string(), hands[2].to_string());
+        assert_eq!("QQQJA".to_string(), hands[3].to_string());
+        assert_eq!("KTJJT".to_string(), hands[4].to_string());
+    }
+
+    #[test]
+    fn test_hand_types() {
+        assert_eq!(CamelHandType::HighCard, hand("2K4T6").hand_type());
+        assert_eq!(CamelHandType::OnePair, hand("4QA2Q").hand_type());
+        assert_eq!(CamelHandType::TwoPair, hand("AA655").hand_type());
+        assert_eq!(CamelHandType::ThreeOfAKind, hand("9299Q").hand_type());
+        assert_eq!(CamelHandType::FullHouse, hand("56565").hand_type());
+        assert_eq!(CamelHandType::FourOfAKind, hand("JJJ3J").hand_type());
+        assert_eq!(CamelHandType::FiveOfAKind, hand("77777").hand_type());
+    }
+
+    #[test]
+    fn test_malformed_lines() {
+        let error = Solver::parse_line("32T1K 765").err().unwrap();
+        assert_eq!("Not a card", error.message());
+        assert_eq!((4, 1), (error.location().column, error.location().width));
+
+        let error = Solver::parse_line("32T3 765").err().unwrap();
+        assert_eq!("A hand must have five cards", error.message());
+        assert_eq!((1, 4), (error.location().column, error.location().width));
+
+        let error = Solver::parse_line("32T3K x65").err().unwrap();
+        assert!(matches!(error, InputError::InvalidValue { .. }));
+        assert_eq!((7, 3), (error.location().column, error.location().width));
+
+        assert!(Solver::parse_line("32T3K").is_err());
+        assert!(Solver::parse_line("").is_err());
+        assert!("32T3".parse::<CamelHand>().is_err());
+    }
+
+    #[test]
+    fn test_check_reports_every_hand() {
+        let input = ["32T3K 765", "T55J 684", "KK677 28", "KTJJT2 220"].map(String::from);
+        let lines = Solver::default()
+            .check(&input)
+            .iter()
+            .map(|p| p.location().line)
+            .collect::<Vec<_>>();
+        assert_eq!(vec![2, 4], lines);
+    }
+
+    // Tries every card in place of each joker.
+    fn brute_force_hand_type(hand: &str) -> CamelHandType {
+        match hand.find('J') {
+            None => hand.parse::<CamelHand>().unwrap().hand_type(),
+            Some(i) => CARDS
+                .chars()
+                .filter(|&c| c != 'J')
+                .map(|c| brute_force_hand_type(&format!("{}{}{}", &hand[..i], c, &hand[i + 1..])))
+                .max()
+                .unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_jokers_wild_matches_brute_force() {
+        for line in generate::input(2023, DAY, 500, 1).unwrap() {
+            let (hand, _bet) = Solver::parse_line(&line).unwrap();
+            let mut wild = hand.clone();
+            wild.jokers_wild();
+            // A hand of five jokers has nothing to copy, so it stays five of a kind
+            let expected = match hand.to_string().as_str() {
+                "JJJJJ" => CamelHandType::FiveOfAKind,
+                cards => brute_force_hand_type(cards),
+            };
+            assert_eq!(expected, wild.hand_type(), "{}", hand);
+        }
+    }
+}
@@ -0,0 +1,296 @@
+use crate::advent::{AdventSolver, Answer};
+use crate::cancel;
+use crate::error::InputError;
+use crate::progress::Progress;
+use anyhow::{format_err, Error};
+use lazy_static::lazy_static;
+use log::debug;
+use num::integer::lcm;
+use regex::Regex;
+use std::collections::HashMap;
+use std::str::FromStr;
+use strum::{self, EnumString};
+
+pub const DAY: u32 = 8;
+pub const TITLE: &str = "Haunted Wasteland";
+
+#[derive(Default)]
+pub struct Solver {
+    map: DesertMap,
+}
+
+impl AdventSolver for Solver {
+    fn parse(&mut self, input: &[String]) -> Result<(), Error> {
+        self.map = DesertMap::new(input)?;
+        Ok(())
+    }
+
+    fn check(&mut self, input: &[String]) -> Vec<InputError> {
+        DesertMap::check(input)
+    }
+
+    fn part_one(&self) -> Result<Answer, Error> {
+        Ok(Answer::new("Steps to ZZZ", self.map.steps_to("ZZZ")?))
+    }
+
+    fn part_two(&self) -> Result<Answer, Error> {
+        Ok(Answer::new("Steps to ??Z", self.map.parallel_steps_to_z()?))
+    }
+}
+
+lazy_static! {
+    static ref MAP_ELEMENT: Regex =
+        Regex::new(r"^(?P<start>\w+) = \((?P<left>\w+), (?P<right>\w+)\)$").unwrap();
+}
+
+#[derive(Clone, Copy, Debug, EnumString)]
+pub enum Step {
+    #[strum(serialize = "L")]
+    Left,
+    #[strum(serialize = "R")]
+    Right,
+}
+
+#[derive(Default)]
+pub struct DesertMap {
+    map: HashMap<String, (String, String)>,
+    steps: Vec<Step>,
+}
+
+impl DesertMap {
+    pub fn new<S: AsRef<str>>(input: &[S]) -> Result<Self, InputError> {
+        let (map, problems) = Self::read(input);
+        match problems.into_iter().next() {
+            Some(problem) => Err(problem),
+            None => Ok(map),
+        }
+    }
+
+    /// Finds every problem in the input, rather than just the first.
+    pub fn check<S: AsRef<str>>(input: &[S]) -> Vec<InputError> {
+        Self::read(input).1
+    }
+
+    // Reads as much of the map as possible, carrying on past bad lines.
+    fn read<S: AsRef<str>>(input: &[S]) -> (Self, Vec<InputError>) {
+        let mut problems = Vec::new();
+        let directions = input.first().map_or("", |line| line.as_ref());
+        if directions.is_empty() {
+            problems.push(InputError::missing("Missing directions", directions).on_line(0));
+        }
+        let mut steps = Vec::new();
+        for (i, c) in directions.char_indices() {
+            match Step::from_str(&c.to_string()) {
+                Ok(step) => steps.push(step),
+                Err(_) => problems.push(
+                    InputError::malformed_at("Not a direction", directions, i..i + c.len_utf8())
+                        .on_line(0),
+                ),
+            }
+        }
+        if let Some(line) = input.get(1).map(|line| line.as_ref()) {
+            if !line.is_empty() {
+                problems.push(InputError::malformed("Expected a blank line", line).on_line(1));
+            }
+        }
+
+        let mut map = HashMap::new();
+        for (i, line) in input.iter().enumerate().skip(2) {
+            let line = line.as_ref();
+            match MAP_ELEMENT.captures(line) {
+                Some(caps) => {
+                    map.insert(
+                        caps["start"].to_string(),
+                        (caps["left"].to_string(), caps["right"].to_string()),
+                    );
+                }
+                None => {
+                    problems.push(InputError::malformed("Couldn't parse element", line).on_line(i))
+                }
+            }
+        }
+
+        // Every node has to lead somewhere on the map, or walking it would fall off the edge.
+        for (i, line) in input.iter().enumerate().skip(2) {
+            let line = line.as_ref();
+            let Some(caps) = MAP_ELEMENT.captures(line) else {
+                continue;
+            };
+            for name in ["left", "right"] {
+                let node = caps.name(name).unwrap();
+                if !map.contains_key(node.as_str()) {
+                    problems.push(
+                        InputError::invalid_at("No such node", line, node.range()).on_line(i),
+                    );
+                }
+            }
+        }
+
+        (DesertMap { map, steps }, problems)
+    }
+
+    pub fn steps_to(&self, target: &str) -> Result<u64, Error> {
+        for node in ["AAA", target] {
+            if !self.map.contains_key(node) {
+                return Err(format_err!("No node named {}", node));
+            }
+        }
+        let mut total_steps = 0;
+        let mut current_node = "AAA";
+        let mut current_step = 0;
+        while current_node != target {
+            cancel::checkpoint()?;
+            let next_nodes = &self.map[current_node];
+            current_node = match self.steps[current_step] {
+                Step::Left => &next_nodes.0,
+                Step::Right => &next_nodes.1,
+            };
+            current_step = (current_step + 1) % self.steps.len();
+            total_steps += 1;
+        }
+        Ok(total_steps)
+    }
+
+    pub fn parallel_steps_to_z(&self) -> Result<u64, Error> {
+        // Each ending node ??Z will be on a cycle of length L. If we start ??A somewhere in the
+        // middle of that cycle, then there would be some offset in addition to a multiple of
+        // cycles to consider when trying to land on ??Z. (Similarly, if we step from ??A into some
+        // cycle that doesn't include ??A, it may have a different length than the offset.) Then we
+        // would have to figure out the offset and length for each cycle and then find a number of
+        // steps that lines all the cycles up on the ending nodes ??Z.
+        //
+        // However, experimentally it seems my input has the cycle length == initial offset, so the
+        // answer is a simple LCM of the cycle lengths.
+        let mut cycle_lengths: Vec<u64> = Vec::new();
+        let mut progress = Progress::new("Steps walked", None);
+        for starting_node in self.map.keys().filter(|k| k.ends_with("A")) {
+            let mut current_step = 0;
+            let mut steps = 0;
+            let mut current_node = starting_node;
+            loop {
+                cancel::checkpoint()?;
+                let next_nodes = &self.map[current_node];
+                current_node = match self.steps[current_step] {
+                    Step::Left => &next_nodes.0,
+                    Step::Right => &next_nodes.1,
+                };
+                current_step = (current_step + 1) % self.steps.len();
+                steps += 1;
+                progress.advance(1);
+                if current_node.ends_with("Z") {
+                    debug!(
+                        "{} reaches {} in {} steps",
+                        starting_node, current_node, steps
+                    );
+                    cycle_lengths.push(steps);
+                    break;
+                }
+            }
+        }
+
+        let steps = cycle_lengths
+            .iter()
+            .fold(1, |result, &cycle| lcm(result, cycle));
+        debug!("LCM of cycle lengths {:?} is {}", cycle_lengths, steps);
+        Ok(steps)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DesertMap, Step, DAY};
+    use crate::error::InputError;
+    use crate::generate;
+    use crate::util::io;
+    use lazy_static::lazy_static;
+
+    lazy_static! {
+        static ref EX1_IN: Vec<String> =
+            io::read_file_as_lines("inputs/2023/examples/day08-ex1.txt").unwrap();
+        static ref EX2_IN: Vec<String> =
+            io::read_file_as_lines("inputs/2023/examples/day08-ex2.txt").unwrap();
+        static ref EX3_IN: Vec<String> =
+            io::read_file_as_lines("inputs/2023/examples/day08-ex3.txt").unwrap();
+    }
+
+    #[test]
+    fn test_ex1_steps_to_zzz() {
+        let map = DesertMap::new(&EX1_IN).unwrap();
+        assert_eq!(2, map.steps_to("ZZZ").unwrap());
+    }
+
+    #[test]
+    fn test_ex2_steps_to_zzz() {
+        let map = DesertMap::new(&EX2_IN).unwrap();
+        assert_eq!(6, map.steps_to("ZZZ").unwrap());
+    }
+
+    #[test]
+    fn test_ex3_parallel_steps_to_z() {
+        let map = DesertMap::new(&EX3_IN).unwrap();
+        assert_eq!(6, map.parallel_steps_to_z().unwrap());
+    }
+
+    #[test]
+    fn test_malformed_map() {
+        let error = DesertMap::new(&["LXR"]).err().unwrap();
+        assert_eq!((1, 2), (error.location().line, error.location().column));
+
+        let error = DesertMap::new(&["LR", "", "AAA = (BBB, ZZZ)", "ZZZ = (ZZZ, ZZZ)"])
+            .err()
+            .unwrap();
+        assert!(matches!(error, InputError::InvalidValue { .. }));
+        let l = error.location();
+        assert_eq!((3, 8, 3), (l.line, l.column, l.width));
+
+        assert!(DesertMap::new::<&str>(&[]).is_err());
+        let problems = DesertMap::check(&["LXR", "", "AAA = (BBB, CCC)", "ZZZ = ZZZ"]);
+        let found = problems
+            .iter()
+            .map(|p| (p.message(), p.location().line))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                ("Not a direction", 1),
+                ("Couldn't parse element", 4),
+                ("No such node", 3),
+                ("No such node", 3),
+            ],
+            found
+        );
+        assert!(DesertMap::new(&EX3_IN).unwrap().steps_to("ZZZ").is_err());
+    }
+
+    // Walks every ghost at once until they're all on a ??Z node.
+    fn simulate_parallel_steps_to_z(map: &DesertMap) -> u64 {
+        let mut nodes = map
+            .map
+            .keys()
+            .filter(|k| k.ends_with('A'))
+            .collect::<Vec<_>>();
+        let mut steps = 0;
+        while !nodes.iter().all(|node| node.ends_with('Z')) {
+            let step = map.steps[steps as usize % map.steps.len()];
+            for node in nodes.iter_mut() {
+                let next_nodes = &map.map[node.as_str()];
+                *node = match step {
+                    Step::Left => &next_nodes.0,
+                    Step::Right => &next_nodes.1,
+                };
+            }
+            steps += 1;
+        }
+        steps
+    }
+
+    #[test]
+    fn test_lcm_matches_simulation() {
+        for seed in 0..50 {
+            let map = DesertMap::new(&generate::input(2023, DAY, 4, seed).unwrap()).unwrap();
+            assert_eq!(
+                simulate_parallel_steps_to_z(&map),
+                map.parallel_steps_to_z().unwrap()
+            );
+        }
+    }
+}
@@ -0,0 +1,12 @@
+//! Advent of Code 2023. Every day declared here is listed in `REGISTRY` (generated by `build.rs`).
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+
+include!(concat!(env!("OUT_DIR"), "/y2023_registry.rs"));
@@ -0,0 +1,174 @@
+use crate::runner::{DayResult, Status};
+use anyhow::{format_err, Error};
+use std::collections::BTreeMap;
+use std::fs;
+
+/// Default location of the known-correct answers file.
+pub const ANSWERS_PATH: &str = "answers.toml";
+
+/// Known-correct answers, keyed by (year, day, part).
+#[derive(Debug, Default)]
+pub struct KnownAnswers {
+    answers: BTreeMap<(u32, u32, u32), String>,
+}
+
+impl KnownAnswers {
+    pub fn load(path: &str) -> Result<KnownAnswers, Error> {
+        Self::parse(&fs::read_to_string(path)?)
+            .map_err(|e| format_err!("Could not read {}: {}", path, e))
+    }
+
+    /// Parses tables of the form `[2023.day01]` with `part1` and `part2` keys.
+    pub fn parse(s: &str) -> Result<KnownAnswers, Error> {
+        let table = s.parse::<toml::Table>()?;
+        let mut answers = BTreeMap::new();
+        for (year_key, days) in table.iter() {
+            let (Ok(year), Some(days)) = (year_key.parse::<u32>(), days.as_table()) else {
+                return Err(format_err!("Unexpected table: [{}]", year_key));
+            };
+            for (day_key, parts) in days.iter() {
+                let key = format!("{}.{}", year_key, day_key);
+                let day = day_key
+                    .strip_prefix("day")
+                    .and_then(|d| d.parse::<u32>().ok())
+                    .ok_or(format_err!("Unexpected table: [{}]", key))?;
+                for (part, value) in parse_part_answers(&key, parts)? {
+                    answers.insert((year, day, part), value);
+                }
+            }
+        }
+        Ok(KnownAnswers { answers })
+    }
+
+    pub fn get(&self, year: u32, day: u32, part: u32) -> Option<&str> {
+        self.answers.get(&(year, day, part)).map(|s| s.as_str())
+    }
+}
+
+/// Parses a table of `part1`/`part2` answers, which may be written as strings or integers. `key`
+/// is the table's name, used in error messages.
+pub fn parse_part_answers(key: &str, parts: &toml::Value) -> Result<BTreeMap<u32, String>, Error> {
+    let parts = parts
+        .as_table()
+        .ok_or(format_err!("Expected [{}] to be a table", key))?;
+    let mut answers = BTreeMap::new();
+    for (part_key, value) in parts.iter() {
+        let part = match part_key.as_str() {
+            "part1" => 1,
+            "part2" => 2,
+            _ => return Err(format_err!("Unexpected key {}.{}", key, part_key)),
+        };
+        let value = match value {
+            toml::Value::String(s) => s.clone(),
+            toml::Value::Integer(i) => i.to_string(),
+            _ => {
+                return Err(format_err!(
+                    "Expected {}.{} to be a string or integer",
+                    key,
+                    part_key
+                ))
+            }
+        };
+        answers.insert(part, value);
+    }
+    Ok(answers)
+}
+
+/// The outcome of checking solver results against known answers.
+pub struct Verification {
+    pub report: String,
+    pub failures: usize,
+}
+
+/// Compares every solved part against its known answer for `year`. Changed answers and solver
+/// errors count as failures; skipped days and parts with no recorded answer are reported but
+/// don't fail.
+pub fn verify(year: u32, results: &[DayResult], known: &KnownAnswers) -> Verification {
+    let mut report = String::new();
+    let mut failures = 0;
+    for result in results.iter() {
+        match &result.status {
+            Status::Solved(solution) => {
+                for part in solution.parts.iter() {
+                    let actual = &part.answer.value;
+                    let prefix = format!("Day {} part {}", result.day, part.part);
+                    match known.get(year, result.day, part.part) {
+                        Some(expected) if expected == actual => {
+                            report.push_str(&format!("{}: ok\n", prefix));
+                        }
+                        Some(expected) => {
+                            failures += 1;
+                            report.push_str(&format!(
+                                "{}: changed\n  - {}\n  + {}\n",
+                                prefix, expected, actual
+                            ));
+                        }
+                        None => {
+                            report.push_str(&format!(
+                                "{}: no recorded answer ({})\n",
+                                prefix, actual
+                            ));
+                        }
+                    }
+                }
+            }
+            Status::Skipped(reason) => {
+                report.push_str(&format!("Day {}: skipped ({})\n", result.day, reason));
+            }
+            Status::Failed(e) => {
+                failures += 1;
+                report.push_str(&format!("Day {}: error: {}\n", result.day, e));
+            }
+            Status::TimedOut(timeout) => {
+                failures += 1;
+                report.push_str(&format!(
+                    "Day {}: timed out after {:?}\n",
+                    result.day, timeout
+                ));
+            }
+        }
+    }
+    report.push_str(&match failures {
+        0 => "All answers verified.\n".to_string(),
+        n => format!("{} failure(s).\n", n),
+    });
+    Verification { report, failures }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::testing::solved;
+
+    #[test]
+    fn test_parse() {
+        let known = KnownAnswers::parse("[2023.day01]\npart1 = 142\npart2 = \"281\"\n").unwrap();
+        assert_eq!(Some("142"), known.get(2023, 1, 1));
+        assert_eq!(Some("281"), known.get(2023, 1, 2));
+        assert_eq!(None, known.get(2023, 2, 1));
+        assert_eq!(None, known.get(2022, 1, 1));
+        assert!(KnownAnswers::parse("[2023.day01]\npart3 = 1\n").is_err());
+        assert!(KnownAnswers::parse("[2023.dayone]\npart1 = 1\n").is_err());
+        assert!(KnownAnswers::parse("[day01]\npart1 = 1\n").is_err());
+    }
+
+    #[test]
+    fn test_verify() {
+        let known = KnownAnswers::parse("[2023.day01]\npart1 = 1\npart2 = 2\n").unwrap();
+        let verification = verify(2023, &[solved(1, &[1, 3]), solved(2, &[5])], &known);
+        assert_eq!(1, verification.failures);
+        assert_eq!(
+            "Day 1 part 1: ok\n\
+             Day 1 part 2: changed\n  - 2\n  + 3\n\
+             Day 2 part 1: no recorded answer (5)\n\
+             1 failure(s).\n",
+            verification.report
+        );
+    }
+
+    #[test]
+    fn test_committed_answers_file() {
+        let known = KnownAnswers::load(ANSWERS_PATH).unwrap();
+        assert_eq!(Some("54667"), known.get(2023, 1, 1));
+    }
+}
@@ -0,0 +1,372 @@
+use crate::config::{self, Config};
+use anyhow::{format_err, Error};
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use strum::{Display, EnumString};
+
+/// The Advent of Code site. Override with `AOC_BASE_URL`, e.g. to point at a local stand-in.
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Environment variable holding the session cookie value.
+pub const SESSION_ENV: &str = "AOC_SESSION";
+
+/// Environment variable overriding where the session cookie file is read from.
+pub const SESSION_FILE_ENV: &str = "AOC_SESSION_FILE";
+
+const USER_AGENT: &str = "github.com/mmueller/advent2023";
+
+lazy_static! {
+    static ref WAIT_RE: Regex =
+        Regex::new(r"You have (?:(?P<minutes>\d+)m )?(?P<seconds>\d+)s left to wait").unwrap();
+    static ref RETRY_RE: Regex =
+        Regex::new(r"[Pp]lease wait (?P<minutes>one|\d+) minutes? before trying again").unwrap();
+}
+
+/// What the site said about a submitted answer.
+#[derive(Clone, Copy, Debug, Display, EnumString, Eq, PartialEq)]
+#[strum(serialize_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, with no hint about which direction.
+    Incorrect,
+    /// Submitted too soon after a previous attempt; the answer was not checked.
+    Wait,
+    /// The part has already been solved (or isn't unlocked yet).
+    WrongLevel,
+}
+
+/// A parsed response to an answer submission.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SubmissionResponse {
+    pub verdict: Verdict,
+    /// Seconds to wait before the next submission is allowed, if the site said so.
+    pub cooldown: Option<u64>,
+}
+
+/// Interprets the HTML returned after posting an answer.
+pub fn parse_submission_response(html: &str) -> Result<SubmissionResponse, Error> {
+    let minutes = |m: &str| {
+        if m == "one" {
+            1
+        } else {
+            m.parse::<u64>().unwrap_or(1)
+        }
+    };
+    let retry = RETRY_RE
+        .captures(html)
+        .map(|caps| minutes(&caps["minutes"]) * 60);
+
+    let (verdict, cooldown) = if html.contains("That's the right answer") {
+        (Verdict::Correct, None)
+    } else if html.contains("That's not the right answer") {
+        if html.contains("your answer is too high") {
+            (Verdict::TooHigh, retry)
+        } else if html.contains("your answer is too low") {
+            (Verdict::TooLow, retry)
+        } else {
+            (Verdict::Incorrect, retry)
+        }
+    } else if html.contains("You gave an answer too recently") {
+        let wait = WAIT_RE.captures(html).map(|caps| {
+            let minutes = caps
+                .name("minutes")
+                .map_or(0, |m| m.as_str().parse().unwrap_or(0));
+            minutes * 60 + caps["seconds"].parse::<u64>().unwrap_or(0)
+        });
+        (Verdict::Wait, wait)
+    } else if html.contains("You don't seem to be solving the right level") {
+        (Verdict::WrongLevel, None)
+    } else {
+        return Err(format_err!("Unrecognized response to answer submission"));
+    };
+    Ok(SubmissionResponse { verdict, cooldown })
+}
+
+/// Client for the parts of the Advent of Code site that need a logged-in session.
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Client {
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            agent: ureq::AgentBuilder::new()
+                .timeout(Duration::from_secs(30))
+                .user_agent(USER_AGENT)
+                .build(),
+        }
+    }
+
+    /// Creates a client using `AOC_BASE_URL` (if set) and the session token from `session_token`.
+    pub fn from_env(config: &Config) -> Result<Client, Error> {
+        let base_url = env::var("AOC_BASE_URL").unwrap_or(DEFAULT_BASE_URL.to_string());
+        Ok(Client::new(&base_url, &session_token(config)?))
+    }
+
+    /// Downloads the input for a day of `year`.
+    pub fn fetch_input(&self, year: u32, day: u32) -> Result<String, Error> {
+        let url = format!("{}/{}/day/{}/input", self.base_url, year, day);
+        match self
+            .agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call()
+        {
+            Ok(response) => Ok(response.into_string()?),
+            Err(ureq::Error::Status(code, response)) => Err(format_err!(
+                "Fetching {} failed with status {}: {}",
+                url,
+                code,
+                response.into_string().unwrap_or_default().trim()
+            )),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Posts an answer for one part of a day of `year` and parses the site's response.
+    pub fn submit_answer(
+        &self,
+        year: u32,
+        day: u32,
+        part: u32,
+        answer: &str,
+    ) -> Result<SubmissionResponse, Error> {
+        let url = format!("{}/{}/day/{}/answer", self.base_url, year, day);
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)]);
+        match response {
+            Ok(response) => parse_submission_response(&response.into_string()?),
+            Err(ureq::Error::Status(code, _)) => Err(format_err!(
+                "Submitting to {} failed with status {}",
+                url,
+                code
+            )),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+/// Default location of the session cookie file: the configured `session_file`, or else `session`
+/// in the config directory (`$XDG_CONFIG_HOME/advent`, falling back to `~/.config/advent`).
+pub fn default_session_file(config: &Config) -> Option<PathBuf> {
+    config
+        .session_file
+        .clone()
+        .or_else(|| config::config_dir().map(|dir| dir.join("session")))
+}
+
+/// Reads the session cookie from `AOC_SESSION`, or else from the file named by
+/// `AOC_SESSION_FILE` (default: `default_session_file`).
+pub fn session_token(config: &Config) -> Result<String, Error> {
+    if let Ok(token) = env::var(SESSION_ENV) {
+        return Ok(token.trim().to_string());
+    }
+    let path = env::var_os(SESSION_FILE_ENV)
+        .map(PathBuf::from)
+        .or_else(|| default_session_file(config))
+        .ok_or(format_err!("No session token: set {}", SESSION_ENV))?;
+    let token = fs::read_to_string(&path).map_err(|e| {
+        format_err!(
+            "No session token: set {} or write it to {} ({})",
+            SESSION_ENV,
+            path.display(),
+            e
+        )
+    })?;
+    Ok(token.trim().to_string())
+}
+
+/// Whether a day's input has already been downloaded to `path`. An empty file (such as the one
+/// the `new` command creates) doesn't count.
+pub fn is_cached(path: &Path) -> bool {
+    fs::metadata(path).is_ok_and(|m| m.len() > 0)
+}
+
+/// Downloads the input for a day of `year` to `path` unless it is already there (see
+/// `is_cached`). Returns whether it downloaded.
+pub fn fetch_input_cached(
+    client: &Client,
+    year: u32,
+    day: u32,
+    path: &Path,
+) -> Result<bool, Error> {
+    if is_cached(path) {
+        return Ok(false);
+    }
+    let input = client.fetch_input(year, day)?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, input)?;
+    Ok(true)
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use crate::util::testing::TempPath;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::thread::{self, JoinHandle};
+
+    /// Serves one canned response per entry in `responses` on a local port, returning the base
+    /// URL and a handle that yields each request's method, path, headers and body.
+    pub fn serve(responses: Vec<(u16, String)>) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            let mut requests = Vec::new();
+            for (status, body) in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+                let mut request = String::new();
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some(len) = line.to_lowercase().strip_prefix("content-length:") {
+                        content_length = len.trim().parse().unwrap();
+                    }
+                    request.push_str(&line);
+                    if line == "\r\n" {
+                        break;
+                    }
+                }
+                let mut content = vec![0; content_length];
+                std::io::Read::read_exact(&mut reader, &mut content).unwrap();
+                request.push_str(&String::from_utf8(content).unwrap());
+                requests.push(request);
+                write!(
+                    reader.get_mut(),
+                    "HTTP/1.1 {} X\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+            requests
+        });
+        (base_url, handle)
+    }
+
+    #[test]
+    fn test_fetch_input_cached() {
+        let (base_url, server) = serve(vec![(200, "1abc2\ntreb7uchet\n".to_string())]);
+        let client = Client::new(&base_url, "abc123");
+        let dir = TempPath::new("fetch");
+        let path = dir.join("day01.txt");
+
+        assert!(fetch_input_cached(&client, 2023, 1, &path).unwrap());
+        assert_eq!("1abc2\ntreb7uchet\n", fs::read_to_string(&path).unwrap());
+        // The server only answers once, so a second download would fail.
+        assert!(!fetch_input_cached(&client, 2023, 1, &path).unwrap());
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("GET /2023/day/1/input HTTP/1.1\r\n"));
+        assert!(requests[0].contains("session=abc123"));
+    }
+
+    #[test]
+    fn test_fetch_input_cached_over_empty_file() {
+        let (base_url, server) = serve(vec![(200, "1abc2\n".to_string())]);
+        let client = Client::new(&base_url, "abc123");
+        let dir = TempPath::new("fetch-empty");
+        let path = dir.join("day01.txt");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(&path, "").unwrap();
+
+        assert!(!is_cached(&path));
+        assert!(fetch_input_cached(&client, 2023, 1, &path).unwrap());
+        assert_eq!("1abc2\n", fs::read_to_string(&path).unwrap());
+        assert!(is_cached(&path));
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn test_parse_submission_response() {
+        let check = |html: &str, verdict, cooldown| {
+            assert_eq!(
+                SubmissionResponse { verdict, cooldown },
+                parse_submission_response(html).unwrap()
+            );
+        };
+        check(
+            "<p>That's the right answer!  You are one gold star closer</p>",
+            Verdict::Correct,
+            None,
+        );
+        check(
+            "<p>That's not the right answer; your answer is too high.  If you're stuck, ... \
+             Please wait one minute before trying again.</p>",
+            Verdict::TooHigh,
+            Some(60),
+        );
+        check(
+            "<p>That's not the right answer; your answer is too low. ... \
+             please wait 5 minutes before trying again.</p>",
+            Verdict::TooLow,
+            Some(300),
+        );
+        check(
+            "<p>That's not the right answer.</p>",
+            Verdict::Incorrect,
+            None,
+        );
+        check(
+            "<p>You gave an answer too recently; you have to wait after submitting an answer \
+             before trying again.  You have 1m 35s left to wait.</p>",
+            Verdict::Wait,
+            Some(95),
+        );
+        check(
+            "<p>You gave an answer too recently. You have 12s left to wait.</p>",
+            Verdict::Wait,
+            Some(12),
+        );
+        check(
+            "<p>You don't seem to be solving the right level.</p>",
+            Verdict::WrongLevel,
+            None,
+        );
+        assert!(parse_submission_response("<html>Oops</html>").is_err());
+    }
+
+    #[test]
+    fn test_submit_answer() {
+        let (base_url, server) = serve(vec![(
+            200,
+            "<main><p>That's the right answer!</p></main>".to_string(),
+        )]);
+        let client = Client::new(&base_url, "abc123");
+        assert_eq!(
+            Verdict::Correct,
+            client.submit_answer(2023, 1, 2, "54203").unwrap().verdict
+        );
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("POST /2023/day/1/answer HTTP/1.1\r\n"));
+        assert!(requests[0].ends_with("\r\n\r\nlevel=2&answer=54203"));
+    }
+
+    #[test]
+    fn test_fetch_input_error_status() {
+        let (base_url, server) = serve(vec![(404, "Not found".to_string())]);
+        let client = Client::new(&base_url, "abc123");
+        let error = client.fetch_input(2023, 26).unwrap_err().to_string();
+        assert!(error.contains("status 404: Not found"), "{}", error);
+        server.join().unwrap();
+    }
+}
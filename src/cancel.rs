@@ -0,0 +1,77 @@
+use std::cell::RefCell;
+use std::error;
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// A flag shared between the runner and a solver, set when the solver should give up (e.g. when
+/// it has run past its timeout).
+#[derive(Clone, Debug, Default)]
+pub struct CancelToken(Arc<AtomicBool>);
+
+impl CancelToken {
+    pub fn new() -> CancelToken {
+        CancelToken::default()
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// The error returned from a solver that noticed it was cancelled.
+#[derive(Debug, Eq, PartialEq)]
+pub struct Cancelled;
+
+impl fmt::Display for Cancelled {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Cancelled")
+    }
+}
+
+impl error::Error for Cancelled {}
+
+thread_local! {
+    static CURRENT: RefCell<Option<CancelToken>> = const { RefCell::new(None) };
+}
+
+/// Runs `f` with `token` as the current thread's cancellation token, so that `checkpoint` calls
+/// made by solvers within it can see it.
+pub fn with_token<T, F: FnOnce() -> T>(token: &CancelToken, f: F) -> T {
+    let previous = CURRENT.with(|current| current.replace(Some(token.clone())));
+    let result = f();
+    CURRENT.with(|current| *current.borrow_mut() = previous);
+    result
+}
+
+/// Returns `Cancelled` if the current thread's token has been cancelled. Solvers call this from
+/// loops that can run for a long time (or forever, on bad input); outside of `with_token` it
+/// always succeeds.
+pub fn checkpoint() -> Result<(), Cancelled> {
+    CURRENT.with(|current| match &*current.borrow() {
+        Some(token) if token.is_cancelled() => Err(Cancelled),
+        _ => Ok(()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checkpoint() {
+        assert_eq!(Ok(()), checkpoint());
+        let token = CancelToken::new();
+        with_token(&token, || {
+            assert_eq!(Ok(()), checkpoint());
+            token.cancel();
+            assert_eq!(Err(Cancelled), checkpoint());
+        });
+        // The token only applies within `with_token`
+        assert_eq!(Ok(()), checkpoint());
+    }
+}
@@ -0,0 +1,222 @@
+use crate::runner::OutputFormat;
+use anyhow::{format_err, Error};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Name of the config file, looked for in the current directory and then in `config_dir`.
+pub const CONFIG_FILE: &str = "advent.toml";
+
+/// Default directory of puzzle inputs, which has a subdirectory for each year.
+pub const DEFAULT_INPUT_DIR: &str = "inputs";
+
+/// Year whose puzzles are solved when none is configured.
+pub const DEFAULT_YEAR: u32 = 2023;
+
+/// Settings read from `advent.toml`, which command-line flags then override.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Config {
+    /// Where the file was loaded from, if there was one.
+    pub path: Option<PathBuf>,
+    /// Year whose solvers, inputs and examples are used.
+    pub year: u32,
+    /// Directory of puzzle inputs (`YYYY/dayNN.txt`).
+    pub input_dir: String,
+    pub format: OutputFormat,
+    /// Day to run (or fetch, submit, etc.) when `--day` isn't given.
+    pub day: Option<u32>,
+    /// Seconds after which a solver is given up on.
+    pub timeout: Option<u64>,
+    /// Where the session cookie is read from, unless overridden by environment variables.
+    pub session_file: Option<PathBuf>,
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config {
+            path: None,
+            year: DEFAULT_YEAR,
+            input_dir: DEFAULT_INPUT_DIR.to_string(),
+            format: OutputFormat::Text,
+            day: None,
+            timeout: None,
+            session_file: None,
+        }
+    }
+}
+
+/// The user's config directory: `$XDG_CONFIG_HOME/advent`, falling back to `~/.config/advent`.
+pub fn config_dir() -> Option<PathBuf> {
+    env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".config")))
+        .map(|config| config.join("advent"))
+}
+
+impl Config {
+    /// Loads `advent.toml` from the current directory, or else from `config_dir`. With neither,
+    /// this is the default config.
+    pub fn load() -> Result<Config, Error> {
+        let candidates = [
+            Some(PathBuf::from(CONFIG_FILE)),
+            config_dir().map(|dir| dir.join(CONFIG_FILE)),
+        ];
+        match candidates.into_iter().flatten().find(|path| path.exists()) {
+            Some(path) => Self::load_from(&path),
+            None => Ok(Config::default()),
+        }
+    }
+
+    pub fn load_from(path: &Path) -> Result<Config, Error> {
+        let config = Self::parse(&fs::read_to_string(path)?)
+            .map_err(|e| format_err!("Could not read {}: {}", path.display(), e))?;
+        Ok(Config {
+            path: Some(path.to_path_buf()),
+            ..config
+        })
+    }
+
+    /// Parses settings, any of which may be left out:
+    ///
+    /// ```toml
+    /// year = 2023
+    /// input_dir = "inputs"
+    /// format = "text"
+    /// day = 5
+    /// timeout = 30
+    /// session_file = "/home/me/.config/advent/session"
+    /// ```
+    pub fn parse(s: &str) -> Result<Config, Error> {
+        let table = s.parse::<toml::Table>()?;
+        let mut config = Config::default();
+        for (key, value) in table.iter() {
+            let expected = |kind| format_err!("Expected {} to be {}", key, kind);
+            match key.as_str() {
+                "year" => {
+                    config.year = value
+                        .as_integer()
+                        .and_then(|year| u32::try_from(year).ok())
+                        .ok_or(expected("a year"))?
+                }
+                "input_dir" => {
+                    config.input_dir = value.as_str().ok_or(expected("a string"))?.to_string()
+                }
+                "format" => {
+                    config.format = value
+                        .as_str()
+                        .and_then(|format| format.parse().ok())
+                        .ok_or(expected("\"text\" or \"json\""))?
+                }
+                "day" => {
+                    config.day = Some(
+                        value
+                            .as_integer()
+                            .and_then(|day| u32::try_from(day).ok())
+                            .ok_or(expected("a day number"))?,
+                    )
+                }
+                "timeout" => {
+                    config.timeout = Some(
+                        value
+                            .as_integer()
+                            .and_then(|secs| u64::try_from(secs).ok())
+                            .ok_or(expected("a number of seconds"))?,
+                    )
+                }
+                "session_file" => {
+                    config.session_file =
+                        Some(PathBuf::from(value.as_str().ok_or(expected("a string"))?))
+                }
+                _ => return Err(format_err!("Unknown setting {}", key)),
+            }
+        }
+        Ok(config)
+    }
+
+    /// Renders the settings in the config file's format, noting where they were loaded from and
+    /// which are unset.
+    pub fn to_toml(&self) -> String {
+        let mut toml = match &self.path {
+            Some(path) => format!("# Loaded from {}\n", path.display()),
+            None => "# No config file found, using defaults\n".to_string(),
+        };
+        let setting = |key: &str, value: Option<toml::Value>| match value {
+            Some(value) => format!("{} = {}\n", key, value),
+            None => format!("# {} is not set\n", key),
+        };
+        let string = |s: &str| Some(toml::Value::String(s.to_string()));
+        toml.push_str(&setting(
+            "year",
+            Some(toml::Value::Integer(self.year.into())),
+        ));
+        toml.push_str(&setting("input_dir", string(&self.input_dir)));
+        toml.push_str(&setting("format", string(&self.format.to_string())));
+        toml.push_str(&setting(
+            "day",
+            self.day.map(|day| toml::Value::Integer(day.into())),
+        ));
+        toml.push_str(&setting(
+            "timeout",
+            self.timeout.map(|secs| toml::Value::Integer(secs as i64)),
+        ));
+        toml.push_str(&setting(
+            "session_file",
+            self.session_file
+                .as_ref()
+                .and_then(|path| string(&path.to_string_lossy())),
+        ));
+        toml
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let config = Config::parse(
+            "year = 2022\ninput_dir = \"puzzles\"\nformat = \"json\"\nday = 5\ntimeout = 30\n\
+             session_file = \"/tmp/session\"\n",
+        )
+        .unwrap();
+        assert_eq!(
+            Config {
+                path: None,
+                year: 2022,
+                input_dir: "puzzles".to_string(),
+                format: OutputFormat::Json,
+                day: Some(5),
+                timeout: Some(30),
+                session_file: Some(PathBuf::from("/tmp/session")),
+            },
+            config
+        );
+        assert_eq!(Config::default(), Config::parse("").unwrap());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(Config::parse("format = \"yaml\"").is_err());
+        assert!(Config::parse("day = \"five\"").is_err());
+        assert!(Config::parse("timeout = -1").is_err());
+        assert!(Config::parse("year = \"2023\"").is_err());
+        assert_eq!(
+            "Unknown setting inputs",
+            Config::parse("inputs = \"x\"").unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn test_to_toml_round_trips() {
+        let config = Config {
+            year: 2015,
+            day: Some(8),
+            timeout: Some(10),
+            ..Default::default()
+        };
+        let toml = config.to_toml();
+        assert!(toml.contains("# session_file is not set\n"));
+        assert_eq!(config, Config::parse(&toml).unwrap());
+    }
+}
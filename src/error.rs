@@ -0,0 +1,199 @@
+use std::error;
+use std::fmt;
+use std::ops::Range;
+use std::str::FromStr;
+
+/// Where in the puzzle input a problem was found.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Location {
+    pub day: Option<u32>,
+    pub file: Option<String>,
+    /// 1-based line number, or 0 if not known yet.
+    pub line: usize,
+    /// 1-based column of the first offending character.
+    pub column: usize,
+    /// Number of offending characters.
+    pub width: usize,
+    /// The full text of the offending line.
+    pub text: String,
+}
+
+/// A problem with puzzle input. Parsers return these rather than panicking, so that malformed
+/// input is always reported with the line and column it was found at.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum InputError {
+    /// A line (or part of one) doesn't have the expected format.
+    Malformed { message: String, location: Location },
+    /// A value has the right format but can't be used, e.g. a number too large to fit.
+    InvalidValue { message: String, location: Location },
+    /// Something the puzzle needs is absent from the input.
+    Missing { message: String, location: Location },
+}
+
+impl InputError {
+    /// The whole of `text` is malformed.
+    pub fn malformed<M: ToString>(message: M, text: &str) -> InputError {
+        InputError::Malformed {
+            message: message.to_string(),
+            location: Location::spanning(text, 0..text.len()),
+        }
+    }
+
+    /// The part of `text` at byte offsets `span` is malformed.
+    pub fn malformed_at<M: ToString>(message: M, text: &str, span: Range<usize>) -> InputError {
+        InputError::Malformed {
+            message: message.to_string(),
+            location: Location::spanning(text, span),
+        }
+    }
+
+    /// The value in `text` at byte offsets `span` is invalid.
+    pub fn invalid_at<M: ToString>(message: M, text: &str, span: Range<usize>) -> InputError {
+        InputError::InvalidValue {
+            message: message.to_string(),
+            location: Location::spanning(text, span),
+        }
+    }
+
+    /// Something is missing; `text` is the line where it was expected (possibly empty).
+    pub fn missing<M: ToString>(message: M, text: &str) -> InputError {
+        InputError::Missing {
+            message: message.to_string(),
+            location: Location::spanning(text, 0..text.len()),
+        }
+    }
+
+    /// Sets the line number, given the 0-based index of the line in the input.
+    pub fn on_line(mut self, index: usize) -> InputError {
+        self.location_mut().line = index + 1;
+        self
+    }
+
+    pub fn message(&self) -> &str {
+        match self {
+            InputError::Malformed { message, .. }
+            | InputError::InvalidValue { message, .. }
+            | InputError::Missing { message, .. } => message,
+        }
+    }
+
+    pub fn location(&self) -> &Location {
+        match self {
+            InputError::Malformed { location, .. }
+            | InputError::InvalidValue { location, .. }
+            | InputError::Missing { location, .. } => location,
+        }
+    }
+
+    pub fn location_mut(&mut self) -> &mut Location {
+        match self {
+            InputError::Malformed { location, .. }
+            | InputError::InvalidValue { location, .. }
+            | InputError::Missing { location, .. } => location,
+        }
+    }
+}
+
+/// Parses the number at byte offsets `span` of `text`, pointing at it if it's invalid.
+pub fn parse_number<T>(text: &str, span: Range<usize>) -> Result<T, InputError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    text[span.clone()]
+        .parse::<T>()
+        .map_err(|e| InputError::invalid_at(format!("Invalid number ({})", e), text, span))
+}
+
+impl Location {
+    /// Locates the byte range `span` within `text`, converting it to character columns.
+    fn spanning(text: &str, span: Range<usize>) -> Location {
+        let start = span.start.min(text.len());
+        let end = span.end.clamp(start, text.len());
+        Location {
+            column: text[..start].chars().count() + 1,
+            width: text[start..end].chars().count().max(1),
+            text: text.to_string(),
+            ..Default::default()
+        }
+    }
+}
+
+impl fmt::Display for InputError {
+    /// Renders the message, the location and the offending line with the problem underlined:
+    ///
+    /// ```text
+    /// Could not parse card
+    ///  --> inputs/2023/day04.txt:3:10 (day 4)
+    ///   |
+    /// 3 | Card 3:  x 21 53 59 44 | 69 82 63 72 16 21 14  1
+    ///   |          ^
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let location = self.location();
+        writeln!(f, "{}", self.message())?;
+        let mut position = location.file.clone().unwrap_or("input".to_string());
+        if location.line > 0 {
+            position.push_str(&format!(":{}:{}", location.line, location.column));
+        }
+        if let Some(day) = location.day {
+            position.push_str(&format!(" (day {})", day));
+        }
+        let gutter = if location.line > 0 {
+            location.line.to_string()
+        } else {
+            String::new()
+        };
+        let pad = " ".repeat(gutter.len());
+        writeln!(f, "{}--> {}", pad, position)?;
+        writeln!(f, "{} |", pad)?;
+        writeln!(
+            f,
+            "{}",
+            format!("{} | {}", gutter, location.text).trim_end()
+        )?;
+        write!(
+            f,
+            "{} | {}{}",
+            pad,
+            " ".repeat(location.column - 1),
+            "^".repeat(location.width)
+        )
+    }
+}
+
+impl error::Error for InputError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let line = "Card 3:  x 21 53 59 44 | 69 82 63 72 16 21 14  1";
+        let mut error = InputError::malformed_at("Could not parse card", line, 9..10).on_line(2);
+        error.location_mut().day = Some(4);
+        error.location_mut().file = Some("inputs/2023/day04.txt".to_string());
+        assert_eq!(
+            "Could not parse card\n \
+             --> inputs/2023/day04.txt:3:10 (day 4)\n  \
+               |\n\
+             3 | Card 3:  x 21 53 59 44 | 69 82 63 72 16 21 14  1\n  \
+               |          ^",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_columns_count_characters() {
+        let error = InputError::invalid_at("Bad value", "µs 123", 4..7);
+        assert_eq!(4, error.location().column);
+        assert_eq!(3, error.location().width);
+
+        // Empty lines and out-of-range spans still underline one character.
+        let error = InputError::missing("Missing distances", "");
+        assert_eq!((1, 1), (error.location().column, error.location().width));
+        let error = InputError::malformed_at("Unexpected", "abc", 5..9);
+        assert_eq!((4, 1), (error.location().column, error.location().width));
+    }
+}
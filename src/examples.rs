@@ -0,0 +1,134 @@
+use crate::advent;
+use crate::answers::parse_part_answers;
+use crate::config;
+use anyhow::{format_err, Error};
+use std::collections::BTreeMap;
+use std::fs;
+
+/// Directory holding a year's puzzle examples, named `dayNN-<name>.txt`. Examples are committed
+/// along with the solvers, so unlike puzzle inputs they're always under `inputs/`.
+pub fn examples_dir(year: u32) -> String {
+    format!("{}/{}/examples", config::DEFAULT_INPUT_DIR, year)
+}
+
+/// A puzzle example input and the answers the puzzle text gives for it.
+#[derive(Debug)]
+pub struct Example {
+    pub year: u32,
+    pub day: u32,
+    pub name: String,
+    pub expected: BTreeMap<u32, String>,
+}
+
+impl Example {
+    pub fn path(&self) -> String {
+        format!(
+            "{}/day{:02}-{}.txt",
+            examples_dir(self.year),
+            self.day,
+            self.name
+        )
+    }
+}
+
+/// Loads every example listed in the year's examples answers file, in day and name order.
+pub fn load_all(year: u32) -> Result<Vec<Example>, Error> {
+    let path = format!("{}/answers.toml", examples_dir(year));
+    let table = fs::read_to_string(&path)?
+        .parse::<toml::Table>()
+        .map_err(|e| format_err!("Could not read {}: {}", path, e))?;
+    let mut examples = Vec::new();
+    for (key, parts) in table.iter() {
+        let (day, name) = key
+            .strip_prefix("day")
+            .and_then(|k| k.split_once('-'))
+            .and_then(|(day, name)| Some((day.parse::<u32>().ok()?, name.to_string())))
+            .ok_or(format_err!("Expected [dayNN-name], found [{}]", key))?;
+        examples.push(Example {
+            year,
+            day,
+            name,
+            expected: parse_part_answers(key, parts)?,
+        });
+    }
+    examples.sort_by(|a, b| (a.day, &a.name).cmp(&(b.day, &b.name)));
+    Ok(examples)
+}
+
+/// Returns the examples for a day of `year`: all of them, or just the one called `name`.
+pub fn for_day(year: u32, day: u32, name: Option<&str>) -> Result<Vec<Example>, Error> {
+    let examples = load_all(year)?
+        .into_iter()
+        .filter(|e| e.day == day && name.is_none_or(|name| e.name == name))
+        .collect::<Vec<_>>();
+    match (examples.is_empty(), name) {
+        (true, Some(name)) => Err(format_err!("Day {} has no example {}.", day, name)),
+        (true, None) => Err(format_err!("Day {} has no examples.", day)),
+        _ => Ok(examples),
+    }
+}
+
+/// The outcome of running examples through their solvers.
+pub struct ExampleRun {
+    pub report: String,
+    pub failures: usize,
+}
+
+/// Runs each example through the full solver pipeline. Without `part`, only the parts the example
+/// has expected answers for are run, since some examples are only valid for one part.
+pub fn run(examples: &[Example], part: Option<u32>) -> ExampleRun {
+    let mut report = String::new();
+    let mut failures = 0;
+    for example in examples.iter() {
+        let parts = match part {
+            Some(part) => vec![part],
+            None => example.expected.keys().copied().collect(),
+        };
+        for part in parts {
+            let prefix = format!("{} part {}", example.name, part);
+            match advent::solve(example.year, example.day, &example.path(), Some(part)) {
+                Ok(solution) => {
+                    let answer = &solution.parts[0].answer;
+                    let check = match example.expected.get(&part) {
+                        Some(expected) if *expected == answer.value => "ok".to_string(),
+                        Some(expected) => {
+                            failures += 1;
+                            format!("expected {}", expected)
+                        }
+                        None => "no expected answer".to_string(),
+                    };
+                    report.push_str(&format!("{}: {} ({})\n", prefix, answer, check));
+                }
+                Err(e) => {
+                    failures += 1;
+                    report.push_str(&format!("{}: error: {}\n", prefix, e));
+                }
+            }
+        }
+    }
+    ExampleRun { report, failures }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_all_examples_pass() {
+        let examples = load_all(2023).unwrap();
+        let run = run(&examples, None);
+        assert_eq!(0, run.failures, "{}", run.report);
+    }
+
+    #[test]
+    fn test_for_day() {
+        let examples = for_day(2023, 8, None).unwrap();
+        assert_eq!(
+            vec!["ex1", "ex2", "ex3"],
+            examples.iter().map(|e| e.name.as_str()).collect::<Vec<_>>()
+        );
+        assert_eq!("inputs/2023/examples/day08-ex3.txt", examples[2].path());
+        assert_eq!(1, for_day(2023, 8, Some("ex2")).unwrap().len());
+        assert!(for_day(2023, 8, Some("ex4")).is_err());
+    }
+}
@@ -0,0 +1,278 @@
+use crate::advent::y2023::day07::CARDS;
+use anyhow::{format_err, Error};
+use itertools::Itertools;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+
+/// Generates a random, valid puzzle input for a day of `year`. `size` scales the input (usually
+/// it's the number of lines), and the same `seed` always generates the same input.
+pub fn input(year: u32, day: u32, size: usize, seed: u64) -> Result<Vec<String>, Error> {
+    let rng = &mut StdRng::seed_from_u64(seed);
+    Ok(match (year, day) {
+        (2023, 1) => day01(rng, size),
+        (2023, 2) => day02(rng, size),
+        (2023, 3) => day03(rng, size),
+        (2023, 4) => day04(rng, size),
+        (2023, 5) => day05(rng, size),
+        (2023, 6) => day06(rng, size),
+        (2023, 7) => day07(rng, size),
+        (2023, 8) => day08(rng, size),
+        _ => {
+            return Err(format_err!(
+                "No input generator for day {} of {}",
+                day,
+                year
+            ))
+        }
+    })
+}
+
+const SPELLED_OUT_DIGITS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// `size` calibration lines of letters, digits and spelled-out digits, each with at least one
+/// digit.
+pub fn day01(rng: &mut StdRng, size: usize) -> Vec<String> {
+    (0..size)
+        .map(|_| {
+            let mut line = String::new();
+            for _ in 0..rng.gen_range(1..=6) {
+                match rng.gen_range(0..3) {
+                    0 => line.push(rng.gen_range(b'a'..=b'z') as char),
+                    1 => line.push(rng.gen_range(b'1'..=b'9') as char),
+                    _ => line.push_str(SPELLED_OUT_DIGITS.choose(rng).unwrap()),
+                }
+            }
+            let at = rng.gen_range(0..=line.len());
+            line.insert(at, rng.gen_range(b'1'..=b'9') as char);
+            line
+        })
+        .collect()
+}
+
+/// `size` games of up to six handfuls of cubes.
+pub fn day02(rng: &mut StdRng, size: usize) -> Vec<String> {
+    (1..=size)
+        .map(|id| {
+            let handfuls = (0..rng.gen_range(1..=6))
+                .map(|_| {
+                    let mut colors = vec!["red", "green", "blue"];
+                    colors.shuffle(rng);
+                    colors.truncate(rng.gen_range(1..=3));
+                    colors
+                        .iter()
+                        .map(|color| format!("{} {}", rng.gen_range(1..=20), color))
+                        .join(", ")
+                })
+                .join("; ");
+            format!("Game {}: {}", id, handfuls)
+        })
+        .collect()
+}
+
+/// A `size` by `size` schematic (at least 5 wide) of numbers and symbols.
+pub fn day03(rng: &mut StdRng, size: usize) -> Vec<String> {
+    let width = size.max(5);
+    let mut grid = vec![vec!['.'; width]; size];
+    for row in grid.iter_mut() {
+        // Numbers are at least one '.' apart, so they don't run together
+        let mut col = rng.gen_range(0..3);
+        while col < width {
+            let len = rng.gen_range(1..=3).min(width - col);
+            let number = rng.gen_range(10u32.pow(len as u32 - 1)..10u32.pow(len as u32));
+            for (i, digit) in number.to_string().chars().enumerate() {
+                row[col + i] = digit;
+            }
+            col += len + rng.gen_range(1..=4);
+        }
+    }
+    for _ in 0..size * width / 8 {
+        let (row, col) = (rng.gen_range(0..size), rng.gen_range(0..width));
+        if grid[row][col] == '.' {
+            grid[row][col] = *b"*#+$/@=%&-".choose(rng).unwrap() as char;
+        }
+    }
+    grid.iter().map(|row| row.iter().collect()).collect()
+}
+
+/// `size` scratchcards with five winning numbers and eight picks.
+pub fn day04(rng: &mut StdRng, size: usize) -> Vec<String> {
+    let numbers = |rng: &mut StdRng, count| {
+        rand::seq::index::sample(rng, 99, count)
+            .iter()
+            .map(|n| format!("{:>2}", n + 1))
+            .join(" ")
+    };
+    (1..=size)
+        .map(|id| format!("Card {:>3}: {} | {}", id, numbers(rng, 5), numbers(rng, 8)))
+        .collect()
+}
+
+/// An almanac with `size` seed ranges and up to `size` ranges in each map, all within
+/// `0..size * 100` so that every seed can be checked by brute force.
+pub fn day05(rng: &mut StdRng, size: usize) -> Vec<String> {
+    let max = size.max(1) as u64 * 100;
+    let seeds = (0..size)
+        .flat_map(|_| {
+            let start = rng.gen_range(0..max);
+            [start, rng.gen_range(1..=max - start)]
+        })
+        .join(" ");
+    let mut lines = vec![format!("seeds: {}", seeds)];
+    let resources = [
+        "seed",
+        "soil",
+        "fertilizer",
+        "water",
+        "light",
+        "temperature",
+        "humidity",
+        "location",
+    ];
+    for (source, dest) in resources.iter().tuple_windows() {
+        lines.push(String::new());
+        lines.push(format!("{}-to-{} map:", source, dest));
+        // Pairs of distinct cut points make source ranges that can't overlap
+        let count = rng.gen_range(1..=size.max(1));
+        let mut cuts = rand::seq::index::sample(rng, max as usize, count * 2).into_vec();
+        cuts.sort();
+        let mut rows = cuts
+            .chunks(2)
+            .map(|cut| {
+                let (start, len) = (cut[0] as u64, (cut[1] - cut[0]) as u64);
+                format!("{} {} {}", rng.gen_range(0..max), start, len)
+            })
+            .collect::<Vec<_>>();
+        rows.shuffle(rng);
+        lines.extend(rows);
+    }
+    lines
+}
+
+/// Up to four races (`size` is capped so that the badly kerned numbers still fit in a u64), each
+/// with a record that can be beaten.
+pub fn day06(rng: &mut StdRng, size: usize) -> Vec<String> {
+    let races = (0..size.clamp(1, 4))
+        .map(|_| {
+            let time = rng.gen_range(2..100u64);
+            let hold = rng.gen_range(1..time);
+            (time, hold * (time - hold) - 1)
+        })
+        .collect::<Vec<_>>();
+    vec![
+        format!(
+            "Time:    {}",
+            races.iter().map(|r| format!("{:>5}", r.0)).join("")
+        ),
+        format!(
+            "Distance:{}",
+            races.iter().map(|r| format!("{:>5}", r.1)).join("")
+        ),
+    ]
+}
+
+/// `size` hands with bets of up to 1000.
+pub fn day07(rng: &mut StdRng, size: usize) -> Vec<String> {
+    let cards = CARDS.chars().collect::<Vec<_>>();
+    (0..size)
+        .map(|_| {
+            let hand = (0..5)
+                .map(|_| cards.choose(rng).unwrap())
+                .collect::<String>();
+            format!("{} {}", hand, rng.gen_range(1..=1000))
+        })
+        .collect()
+}
+
+// Letters for node names that don't end a name with A or Z.
+const NODE_LETTERS: &[u8] = b"BCDEFGHIJKLMNOPQRSTUVWXY";
+
+fn node_name(n: usize, len: usize) -> String {
+    let base = NODE_LETTERS.len();
+    (0..len)
+        .rev()
+        .map(|i| NODE_LETTERS[n / base.pow(i as u32) % base] as char)
+        .collect()
+}
+
+/// A network of up to four ghosts (AAA being the first), where each ghost reaches its ??Z node
+/// after a multiple of the directions' length and then loops back to it in the same number of
+/// steps, as the LCM shortcut relies on. `size` bounds the number of ghosts and their loop
+/// lengths.
+pub fn day08(rng: &mut StdRng, size: usize) -> Vec<String> {
+    let size = size.max(1);
+    let directions = (0..rng.gen_range(1..=3))
+        .map(|_| if rng.gen() { 'L' } else { 'R' })
+        .collect::<String>();
+    let ghosts = rng.gen_range(1..=size.min(4));
+    let mut names = 0..;
+    // Each node, the node it leads to next, and the step (mod the directions' length) taken there
+    let mut nodes: Vec<(String, String, usize)> = Vec::new();
+    for ghost in 0..ghosts {
+        let (start, end) = match ghost {
+            0 => ("AAA".to_string(), "ZZZ".to_string()),
+            _ => (node_name(ghost, 2) + "A", node_name(ghost, 2) + "Z"),
+        };
+        let length = directions.len() * rng.gen_range(1..=size.min(4));
+        // The path from the start to the end, then around again from the second node
+        let mut path = vec![start];
+        path.extend((1..length).map(|_| node_name(names.next().unwrap(), 3)));
+        path.push(end);
+        for (i, pair) in path.windows(2).enumerate() {
+            nodes.push((pair[0].clone(), pair[1].clone(), i % directions.len()));
+        }
+        nodes.push((path[length].clone(), path[1].clone(), 0));
+    }
+    // The other branch of each node leads anywhere, since it's never taken
+    let names = nodes.iter().map(|n| n.0.clone()).collect::<Vec<_>>();
+    let mut lines = nodes
+        .iter()
+        .map(|(node, next, step)| {
+            let other = names.choose(rng).unwrap();
+            match directions.as_bytes()[*step] {
+                b'L' => format!("{} = ({}, {})", node, next, other),
+                _ => format!("{} = ({}, {})", node, other, next),
+            }
+        })
+        .collect::<Vec<_>>();
+    lines.shuffle(rng);
+    [vec![directions, String::new()], lines].concat()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::advent;
+
+    #[test]
+    fn test_generated_inputs_are_valid() {
+        for &day in advent::implemented_days(2023)
+            .iter()
+            .filter(|&&day| day <= 8)
+        {
+            for seed in 0..20 {
+                let input = input(2023, day, 1 + seed as usize % 3, seed).unwrap();
+                let mut solver = advent::entry_for(2023, day).unwrap().create();
+                let problems = solver.check(&input);
+                assert!(
+                    problems.is_empty(),
+                    "day {} seed {}: {}",
+                    day,
+                    seed,
+                    problems[0]
+                );
+                assert!(advent::solve_input(2023, day, &input, None).is_ok());
+            }
+        }
+    }
+
+    #[test]
+    fn test_same_seed_same_input() {
+        assert_eq!(input(2023, 5, 4, 7).unwrap(), input(2023, 5, 4, 7).unwrap());
+        assert_ne!(input(2023, 5, 4, 7).unwrap(), input(2023, 5, 4, 8).unwrap());
+        assert!(input(2023, 26, 4, 7).is_err());
+        assert!(input(2022, 5, 4, 7).is_err());
+    }
+}
@@ -0,0 +1,22 @@
+//! Advent of Code solutions (so far for 2023), along with the runner, answer checking and site
+//! client used by the `advent2023` binary.
+//!
+//! Each year's solvers live in a module such as `advent::y2023`, whose `REGISTRY` lists its days;
+//! `advent::YEARS` lists the years. Every solver implements `advent::AdventSolver`.
+//! The parsed puzzle types (e.g. `advent::y2023::day05::Almanac`) can also be used directly.
+
+pub mod advent;
+pub mod answers;
+pub mod aoc;
+pub mod cancel;
+pub mod config;
+pub mod error;
+pub mod examples;
+pub mod generate;
+pub mod progress;
+pub mod report;
+pub mod runner;
+pub mod scaffold;
+pub mod submissions;
+pub mod util;
+pub mod watch;
@@ -1,25 +1,557 @@
-use argparse::{ArgumentParser, StoreOption};
-
-pub mod advent;
-pub mod util;
+use advent2023::config::Config;
+use advent2023::runner::OutputFormat;
+use advent2023::{
+    advent, answers, aoc, examples, generate, progress, report, runner, scaffold, submissions,
+    util, watch,
+};
+use argparse::{ArgumentParser, IncrBy, Store, StoreOption, StoreTrue};
+use std::fs;
+use std::io::{self, IsTerminal, Write};
+use std::path::Path;
+use std::process;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 fn main() {
+    let mut command: Option<String> = None;
+    let mut title: Option<String> = None;
+    let mut year: Option<u32> = None;
     let mut day: Option<u32> = None;
+    let mut all = false;
+    let mut input: Option<String> = None;
+    let mut part: Option<u32> = None;
+    let mut time = false;
+    let mut bench: Option<usize> = None;
+    let mut verify = false;
+    let mut check = false;
+    let mut format: Option<OutputFormat> = None;
+    let mut list = false;
+    let mut jobs: usize = 1;
+    let mut timeout: Option<u64> = None;
+    let mut quiet = false;
+    let mut verbose: u32 = 0;
+    let mut example: Option<String> = None;
+    let mut all_examples = false;
+    let mut watch = false;
+    let mut size: usize = 10;
+    let mut seed: u64 = 0;
+    let mut input_dir: Option<String> = None;
+    let mut show_config = false;
+    let mut output = report::REPORT_PATH.to_string();
+    let mut html: Option<String> = None;
     {
         let mut parser = ArgumentParser::new();
-        parser.set_description("Advent of Code 2023");
+        parser.set_description("Advent of Code");
+        parser.refer(&mut command).add_argument(
+            "command",
+            StoreOption,
+            "optional command: new (scaffold a new day's module and input files), fetch \
+             (download a day's input), submit (post a part's answer), generate (print a random \
+             input for a day) or report (run every day and write a report of the results)",
+        );
+        parser.refer(&mut year).add_option(
+            &["-y", "--year"],
+            StoreOption,
+            "year of the puzzles (default: year from advent.toml, or 2023)",
+        );
         parser.refer(&mut day).add_option(
             &["-d", "--day"],
             StoreOption,
-            "number of challenge to run",
+            "number of challenge to run (default: day from advent.toml)",
+        );
+        parser
+            .refer(&mut all)
+            .add_option(&["--all"], StoreTrue, "run every implemented day");
+        parser.refer(&mut input).add_option(
+            &["-i", "--input"],
+            StoreOption,
+            "path to puzzle input, or - to read stdin (default: inputs/YYYY/dayNN.txt)",
+        );
+        parser.refer(&mut input_dir).add_option(
+            &["--input-dir"],
+            StoreOption,
+            "directory of puzzle inputs (default: inputs, or input_dir from advent.toml)",
+        );
+        parser.refer(&mut part).add_option(
+            &["-p", "--part"],
+            StoreOption,
+            "only run the given part (1 or 2)",
+        );
+        parser
+            .refer(&mut time)
+            .add_option(&["--time"], StoreTrue, "report parse and solve times");
+        parser.refer(&mut bench).add_option(
+            &["--bench"],
+            StoreOption,
+            "run the solver N times and report timing statistics",
+        );
+        parser.refer(&mut verify).add_option(
+            &["--verify"],
+            StoreTrue,
+            "check answers against answers.toml (all days unless --day is given)",
+        );
+        parser.refer(&mut check).add_option(
+            &["--check"],
+            StoreTrue,
+            "only parse the input and report every problem in it (all days unless --day is given)",
+        );
+        parser.refer(&mut format).add_option(
+            &["--format"],
+            StoreOption,
+            "output format for --day and --all: text or json (one object per day)",
+        );
+        parser.refer(&mut list).add_option(
+            &["--list"],
+            StoreTrue,
+            "list implemented days and whether their input files exist",
+        );
+        parser.refer(&mut jobs).add_option(
+            &["-j", "--jobs"],
+            Store,
+            "number of days to run concurrently with --all or --verify (default: 1)",
+        );
+        parser.refer(&mut timeout).add_option(
+            &["--timeout"],
+            StoreOption,
+            "give up on a day (and report it as timed out) after SECS seconds",
+        );
+        parser.refer(&mut quiet).add_option(
+            &["-q", "--quiet"],
+            StoreTrue,
+            "don't show progress of long-running solvers on stderr",
+        );
+        parser.refer(&mut verbose).add_option(
+            &["-v", "--verbose"],
+            IncrBy(1),
+            "log what solvers are doing on stderr (-v for info, -vv for debug traces, -vvv for \
+             everything)",
+        );
+        parser.refer(&mut example).add_option(
+            &["-e", "--example"],
+            StoreOption,
+            "run the day on the named example from inputs/YYYY/examples instead of its input",
+        );
+        parser.refer(&mut all_examples).add_option(
+            &["--examples"],
+            StoreTrue,
+            "run the day on all of its examples from inputs/YYYY/examples",
+        );
+        parser.refer(&mut title).add_option(
+            &["--title"],
+            StoreOption,
+            "puzzle title for the new command",
+        );
+        parser.refer(&mut watch).add_option(
+            &["--watch"],
+            StoreTrue,
+            "re-run the day whenever its input (or examples, with --example/--examples) changes",
+        );
+        parser.refer(&mut size).add_option(
+            &["--size"],
+            Store,
+            "size of the input for the generate command, usually in lines (default: 10)",
+        );
+        parser.refer(&mut seed).add_option(
+            &["--seed"],
+            Store,
+            "random seed for the generate command (default: 0)",
+        );
+        parser.refer(&mut output).add_option(
+            &["-o", "--output"],
+            Store,
+            "where the report command writes its Markdown report (default: report.md)",
+        );
+        parser.refer(&mut html).add_option(
+            &["--html"],
+            StoreOption,
+            "also write the report command's report as HTML to the given path",
+        );
+        parser.refer(&mut show_config).add_option(
+            &["--show-config"],
+            StoreTrue,
+            "print the settings in effect (advent.toml with command line overrides)",
         );
         parser.parse_args_or_exit();
     }
-    match day {
-        Some(ref day) => match advent::solve(*day) {
-            Ok(_) => {}
-            Err(e) => println!("error: {}", e),
-        },
-        None => println!("--day is required"),
+    let mut settings = match Config::load() {
+        Ok(settings) => settings,
+        Err(e) => {
+            println!("error: {}", e);
+            process::exit(2);
+        }
+    };
+    if let Some(year) = year {
+        settings.year = year;
+    }
+    if let Some(input_dir) = input_dir {
+        settings.input_dir = input_dir;
+    }
+    if let Some(format) = format {
+        settings.format = format;
+    }
+    if timeout.is_some() {
+        settings.timeout = timeout;
+    }
+    if day.is_some() {
+        settings.day = day;
+    }
+    if show_config {
+        print!("{}", settings.to_toml());
+        return;
+    }
+    // Only new and fetch make sense for a year that has no solvers yet
+    if !matches!(command.as_deref(), Some("new" | "fetch")) {
+        if let Err(e) = advent::registry_for(settings.year) {
+            println!("error: {}", e);
+            process::exit(2);
+        }
+    }
+    let year = settings.year;
+    let format = settings.format;
+    let timeout = settings.timeout.map(Duration::from_secs);
+    // --all, --verify and --check cover every day unless --day itself is given
+    let default_day = settings.day;
+    // RUST_LOG can pick out modules, e.g. RUST_LOG=advent2023::advent::y2023::day08=debug
+    let mut logger = env_logger::Builder::from_default_env();
+    if verbose > 0 {
+        logger.filter_level(match verbose {
+            1 => log::LevelFilter::Info,
+            2 => log::LevelFilter::Debug,
+            _ => log::LevelFilter::Trace,
+        });
+    }
+    logger.init();
+    // Progress lines would only be broken up by log messages, or by other days' progress lines
+    // when several days run at once
+    let concurrent =
+        jobs > 1 && (all || (verify && day.is_none()) || command.as_deref() == Some("report"));
+    progress::set_enabled(!quiet && verbose == 0 && !concurrent && io::stdout().is_terminal());
+    match command.as_deref() {
+        None => {}
+        Some("new") => {
+            let Some(day) = default_day else {
+                println!("new requires --day");
+                process::exit(2);
+            };
+            match scaffold::new_day(Path::new("."), &settings, day, title.as_deref()) {
+                Ok(paths) => {
+                    for path in paths.iter() {
+                        println!("wrote {}", path);
+                    }
+                }
+                Err(e) => {
+                    println!("error: {}", e);
+                    process::exit(1);
+                }
+            }
+            return;
+        }
+        Some("fetch") => {
+            let Some(day) = default_day else {
+                println!("fetch requires --day");
+                process::exit(2);
+            };
+            match fetch_input(&settings, day) {
+                Ok(true) => println!("wrote {}", advent::input_path(&settings, day)),
+                Ok(false) => println!("{} already exists", advent::input_path(&settings, day)),
+                Err(e) => {
+                    println!("error: {}", e);
+                    process::exit(1);
+                }
+            }
+            return;
+        }
+        Some("submit") => {
+            let (Some(day), Some(part)) = (default_day, part) else {
+                println!("submit requires --day and --part");
+                process::exit(2);
+            };
+            if !offer_to_fetch(&settings, day) {
+                process::exit(1);
+            }
+            if let Err(e) = submit_answer(&settings, day, part) {
+                println!("error: {}", e);
+                process::exit(1);
+            }
+            return;
+        }
+        Some("generate") => {
+            let Some(day) = default_day else {
+                println!("generate requires --day");
+                process::exit(2);
+            };
+            match generate::input(year, day, size, seed) {
+                Ok(lines) => {
+                    for line in lines.iter() {
+                        println!("{}", line);
+                    }
+                }
+                Err(e) => {
+                    println!("error: {}", e);
+                    process::exit(1);
+                }
+            }
+            return;
+        }
+        Some("report") => {
+            // The report is still useful without recorded answers, it just can't check them
+            let known = if Path::new(answers::ANSWERS_PATH).exists() {
+                match answers::KnownAnswers::load(answers::ANSWERS_PATH) {
+                    Ok(known) => known,
+                    Err(e) => {
+                        println!("error: {}", e);
+                        process::exit(2);
+                    }
+                }
+            } else {
+                answers::KnownAnswers::default()
+            };
+            let start = Instant::now();
+            let results = runner::run_all(&settings, jobs, timeout);
+            let report = report::Report::new(year, &results, &known, start.elapsed());
+            let mut files = vec![(output, report.to_markdown())];
+            if let Some(html) = html {
+                files.push((html, report.to_html()));
+            }
+            for (path, contents) in files.iter() {
+                if let Err(e) = fs::write(path, contents) {
+                    println!("error: could not write {}: {}", path, e);
+                    process::exit(1);
+                }
+                println!("wrote {}", path);
+            }
+            if report.failures > 0 {
+                process::exit(1);
+            }
+            return;
+        }
+        Some(other) => {
+            println!("unknown command: {}", other);
+            process::exit(2);
+        }
+    }
+    if list {
+        print!("{}", runner::list_table(&settings));
+        return;
+    }
+    if check {
+        let inputs = match (day, input) {
+            (Some(day), input) => vec![(
+                day,
+                input.unwrap_or_else(|| advent::input_path(&settings, day)),
+            )],
+            (None, None) => advent::implemented_days(year)
+                .into_iter()
+                .map(|day| (day, advent::input_path(&settings, day)))
+                .collect(),
+            (None, Some(_)) => {
+                println!("--input requires --day");
+                process::exit(2);
+            }
+        };
+        let check = runner::check_inputs(year, &inputs);
+        print!("{}", check.report);
+        if check.problems > 0 {
+            process::exit(1);
+        }
+        return;
+    }
+    if verify {
+        if input.is_some() {
+            println!("--input cannot be combined with --verify");
+            process::exit(2);
+        }
+        let known = match answers::KnownAnswers::load(answers::ANSWERS_PATH) {
+            Ok(known) => known,
+            Err(e) => {
+                println!("error: {}", e);
+                process::exit(2);
+            }
+        };
+        let results = match day {
+            Some(day) => vec![runner::run_day(&settings, day, timeout)],
+            None => runner::run_all(&settings, jobs, timeout),
+        };
+        let verification = answers::verify(year, &results, &known);
+        print!("{}", verification.report);
+        if verification.failures > 0 {
+            process::exit(1);
+        }
+        return;
+    }
+    if all {
+        if input.is_some() || part.is_some() {
+            println!("--input and --part cannot be combined with --all");
+            process::exit(2);
+        }
+        let results = runner::run_all(&settings, jobs, timeout);
+        match format {
+            OutputFormat::Text => print!("{}", runner::summary_table(&results)),
+            OutputFormat::Json => {
+                for result in results.iter() {
+                    println!("{}", runner::json_line(result));
+                }
+            }
+        }
+        return;
+    }
+    match default_day {
+        Some(day) if watch => {
+            let input_path = input.unwrap_or_else(|| advent::input_path(&settings, day));
+            if input_path == util::io::STDIN_PATH {
+                println!("--watch needs an input file, not stdin");
+                process::exit(2);
+            }
+            let examples = if example.is_some() || all_examples {
+                match examples::for_day(year, day, example.as_deref()) {
+                    Ok(examples) => Some(examples),
+                    Err(e) => {
+                        println!("error: {}", e);
+                        process::exit(1);
+                    }
+                }
+            } else {
+                None
+            };
+            watch::watch(year, day, &input_path, part, examples);
+        }
+        Some(day) if example.is_some() || all_examples => {
+            if input.is_some() {
+                println!("--input cannot be combined with --example");
+                process::exit(2);
+            }
+            match examples::for_day(year, day, example.as_deref()) {
+                Ok(examples) => {
+                    let run = examples::run(&examples, part);
+                    print!("{}", run.report);
+                    if run.failures > 0 {
+                        process::exit(1);
+                    }
+                }
+                Err(e) => {
+                    println!("error: {}", e);
+                    process::exit(1);
+                }
+            }
+        }
+        Some(day) => {
+            // In JSON mode a missing input is reported as a skipped day instead
+            let json = format == OutputFormat::Json;
+            if input.is_none() && !json && !offer_to_fetch(&settings, day) {
+                process::exit(1);
+            }
+            let input_path = input.unwrap_or_else(|| advent::input_path(&settings, day));
+            if let Some(iterations) = bench {
+                match runner::bench(year, day, &input_path, part, iterations) {
+                    Ok(phases) => print!("{}", runner::bench_table(iterations, &phases)),
+                    Err(e) => {
+                        println!("error: {}", e);
+                        process::exit(1);
+                    }
+                }
+                return;
+            }
+            let result = runner::run_day_input(year, day, &input_path, part, timeout);
+            if json {
+                println!("{}", runner::json_line(&result));
+            } else {
+                match &result.status {
+                    runner::Status::Solved(solution) => {
+                        for answer in solution.answers().iter() {
+                            println!("{}", answer);
+                        }
+                        if time {
+                            print!("{}", runner::timing_report(solution));
+                        }
+                    }
+                    runner::Status::Failed(e) => println!("error: {}", e),
+                    runner::Status::TimedOut(timeout) => {
+                        println!("error: timed out after {:?}", timeout)
+                    }
+                    runner::Status::Skipped(reason) => println!("{}", reason),
+                }
+            }
+            // A day that wasn't solved is a failure, whichever way it's reported
+            if !matches!(result.status, runner::Status::Solved(_)) {
+                process::exit(1);
+            }
+        }
+        None => println!("--day or --all is required"),
+    }
+}
+
+fn fetch_input(settings: &Config, day: u32) -> Result<bool, anyhow::Error> {
+    let input_path = advent::input_path(settings, day);
+    if aoc::is_cached(Path::new(&input_path)) {
+        return Ok(false);
+    }
+    let client = aoc::Client::from_env(settings)?;
+    aoc::fetch_input_cached(&client, settings.year, day, Path::new(&input_path))
+}
+
+/// If a day's default input is missing or empty, asks whether to download it (when run
+/// interactively). Returns whether the input is now present.
+fn offer_to_fetch(settings: &Config, day: u32) -> bool {
+    let input_path = advent::input_path(settings, day);
+    if aoc::is_cached(Path::new(&input_path)) || advent::entry_for(settings.year, day).is_err() {
+        return true;
+    }
+    if !io::stdin().is_terminal() {
+        println!(
+            "{} not found or empty (run with `fetch --day={}` to download it)",
+            input_path, day
+        );
+        return false;
+    }
+    print!("{} not found or empty. Download it now? [y/N] ", input_path);
+    let _ = io::stdout().flush();
+    let mut reply = String::new();
+    if io::stdin().read_line(&mut reply).is_err() || !reply.trim().eq_ignore_ascii_case("y") {
+        return false;
+    }
+    match fetch_input(settings, day) {
+        Ok(_) => true,
+        Err(e) => {
+            println!("error: {}", e);
+            false
+        }
+    }
+}
+
+/// Solves one part of a day and submits the answer, unless the submission log shows it can't
+/// be right or the site would refuse it.
+fn submit_answer(settings: &Config, day: u32, part: u32) -> Result<(), anyhow::Error> {
+    let year = settings.year;
+    let input_path = advent::input_path(settings, day);
+    let solution = advent::solve(year, day, &input_path, Some(part))?;
+    let answer = &solution.parts[0].answer;
+    println!("{}", answer);
+
+    let mut log = submissions::SubmissionLog::load(Path::new(submissions::SUBMISSIONS_PATH))?;
+    let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+    log.check(year, day, part, &answer.value, now)?;
+
+    let response =
+        aoc::Client::from_env(settings)?.submit_answer(year, day, part, &answer.value)?;
+    log.record(submissions::Attempt {
+        year,
+        day,
+        part,
+        answer: answer.value.clone(),
+        verdict: response.verdict,
+        time: now,
+        cooldown: response.cooldown,
+    })?;
+    let verdict = match response.verdict {
+        aoc::Verdict::Correct => "correct!",
+        aoc::Verdict::TooHigh => "too high",
+        aoc::Verdict::TooLow => "too low",
+        aoc::Verdict::Incorrect => "not the right answer",
+        aoc::Verdict::Wait => "not checked, submitted too recently",
+        aoc::Verdict::WrongLevel => "already solved or not unlocked yet",
+    };
+    match response.cooldown {
+        Some(seconds) => println!("{} (wait {}s before trying again)", verdict, seconds),
+        None => println!("{}", verdict),
     }
+    Ok(())
 }
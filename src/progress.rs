@@ -0,0 +1,125 @@
+use std::io::{self, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// How often progress is redrawn, and how long a loop runs before it's first shown.
+const REDRAW_INTERVAL: Duration = Duration::from_millis(100);
+
+/// How many items go by between looking at the clock.
+const CHECK_INTERVAL: u64 = 1024;
+
+/// Turns progress reporting on or off for the whole process. It's off by default, so that only
+/// the binary (when writing to a terminal) shows it.
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Progress through a long loop, shown on stderr as items done, rate and (if the total is known)
+/// time remaining. Nothing is shown for loops that finish quickly, and the line is cleared when
+/// the `Progress` is dropped.
+pub struct Progress {
+    label: &'static str,
+    total: Option<u64>,
+    done: u64,
+    enabled: bool,
+    start: Instant,
+    next_check: u64,
+    last_draw: Option<Instant>,
+}
+
+impl Progress {
+    pub fn new(label: &'static str, total: Option<u64>) -> Progress {
+        Progress {
+            label,
+            total,
+            done: 0,
+            enabled: ENABLED.load(Ordering::Relaxed),
+            start: Instant::now(),
+            next_check: CHECK_INTERVAL,
+            last_draw: None,
+        }
+    }
+
+    /// Records `n` more items done, redrawing the progress line if it's due.
+    pub fn advance(&mut self, n: u64) {
+        self.done += n;
+        if !self.enabled || self.done < self.next_check {
+            return;
+        }
+        self.next_check = self.done + CHECK_INTERVAL;
+        let now = Instant::now();
+        let since = self.last_draw.unwrap_or(self.start);
+        if now - since >= REDRAW_INTERVAL {
+            self.last_draw = Some(now);
+            let mut stderr = io::stderr();
+            let _ = write!(stderr, "\r\x1b[K{}", self.render(now - self.start));
+            let _ = stderr.flush();
+        }
+    }
+
+    /// The progress line after `elapsed`, e.g. "Seeds: 1.2M/3.0M (5.0M/s, ETA 0.4s)".
+    fn render(&self, elapsed: Duration) -> String {
+        let rate = self.done as f64 / elapsed.as_secs_f64().max(f64::EPSILON);
+        match self.total {
+            Some(total) => {
+                let remaining = total.saturating_sub(self.done) as f64 / rate.max(f64::EPSILON);
+                format!(
+                    "{}: {}/{} ({}/s, ETA {:.1}s)",
+                    self.label,
+                    abbreviate(self.done as f64),
+                    abbreviate(total as f64),
+                    abbreviate(rate),
+                    remaining
+                )
+            }
+            None => format!(
+                "{}: {} ({}/s)",
+                self.label,
+                abbreviate(self.done as f64),
+                abbreviate(rate)
+            ),
+        }
+    }
+}
+
+impl Drop for Progress {
+    fn drop(&mut self) {
+        if self.last_draw.is_some() {
+            let _ = write!(io::stderr(), "\r\x1b[K");
+        }
+    }
+}
+
+// Shortens large counts, e.g. 1234567 to "1.2M".
+fn abbreviate(n: f64) -> String {
+    match n {
+        n if n >= 1e9 => format!("{:.1}G", n / 1e9),
+        n if n >= 1e6 => format!("{:.1}M", n / 1e6),
+        n if n >= 1e3 => format!("{:.1}k", n / 1e3),
+        n => format!("{:.0}", n),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let mut progress = Progress::new("Seeds", Some(3_000_000));
+        progress.advance(1_200_000);
+        assert_eq!(
+            "Seeds: 1.2M/3.0M (600.0k/s, ETA 3.0s)",
+            progress.render(Duration::from_secs(2))
+        );
+
+        let mut progress = Progress::new("Steps", None);
+        progress.advance(500);
+        assert_eq!(
+            "Steps: 500 (250/s)",
+            progress.render(Duration::from_secs(2))
+        );
+    }
+}
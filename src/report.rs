@@ -0,0 +1,250 @@
+use crate::advent;
+use crate::answers::KnownAnswers;
+use crate::runner::{DayResult, Status};
+use std::time::Duration;
+use strum::Display;
+
+/// Default location of the Markdown report written by the `report` command.
+pub const REPORT_PATH: &str = "report.md";
+
+/// A report of a run of every day, with answers checked against the known ones.
+pub struct Report {
+    pub year: u32,
+    rows: Vec<Row>,
+    /// Days whose answers changed, or that failed or timed out.
+    pub failures: usize,
+    /// Wall-clock time of the whole run, which is less than the days' total with `--jobs`.
+    wall_time: Duration,
+}
+
+/// How a day fared, shown in its row's status column (and used as the row's HTML class).
+#[derive(Clone, Copy, Debug, Display, Eq, PartialEq)]
+#[strum(serialize_all = "lowercase")]
+enum RowStatus {
+    /// Every answer matched the known one.
+    Pass,
+    /// Some answer differs from the known one.
+    Fail,
+    /// Solved, but some answer has nothing to check against.
+    Unverified,
+    Skipped,
+    Error,
+    Timeout,
+}
+
+impl RowStatus {
+    fn is_failure(self) -> bool {
+        matches!(
+            self,
+            RowStatus::Fail | RowStatus::Error | RowStatus::Timeout
+        )
+    }
+}
+
+// One day's row of the report table.
+struct Row {
+    day: u32,
+    title: String,
+    /// The answer (or error) cell for each part.
+    parts: [String; 2],
+    parse_time: String,
+    solve_time: String,
+    status: RowStatus,
+}
+
+impl Report {
+    /// Builds the report for a run of `year`'s days that took `wall_time`.
+    pub fn new(
+        year: u32,
+        results: &[DayResult],
+        known: &KnownAnswers,
+        wall_time: Duration,
+    ) -> Report {
+        let mut failures = 0;
+        let mut rows = Vec::new();
+        for result in results.iter() {
+            let title = advent::entry_for(year, result.day)
+                .map(|entry| entry.title.to_string())
+                .unwrap_or_default();
+            let mut row = Row {
+                day: result.day,
+                title,
+                parts: [String::new(), String::new()],
+                parse_time: String::new(),
+                solve_time: String::new(),
+                status: RowStatus::Pass,
+            };
+            match &result.status {
+                Status::Solved(solution) => {
+                    for part in solution.parts.iter() {
+                        let actual = &part.answer.value;
+                        let cell = &mut row.parts[part.part as usize - 1];
+                        match known.get(year, result.day, part.part) {
+                            Some(expected) if expected == actual => *cell = actual.clone(),
+                            Some(expected) => {
+                                *cell = format!("{} (expected {})", actual, expected);
+                                row.status = RowStatus::Fail;
+                            }
+                            None => {
+                                *cell = actual.clone();
+                                if row.status == RowStatus::Pass {
+                                    row.status = RowStatus::Unverified;
+                                }
+                            }
+                        }
+                    }
+                    row.parse_time = format!("{:.2?}", solution.parse_time);
+                    let solve_time = solution.parts.iter().map(|p| p.time).sum::<Duration>();
+                    row.solve_time = format!("{:.2?}", solve_time);
+                }
+                Status::Skipped(reason) => {
+                    row.parts[0] = reason.clone();
+                    row.status = RowStatus::Skipped;
+                }
+                Status::Failed(e) => {
+                    // Only the message of an input error, not its location and underline
+                    row.parts[0] = e.to_string().lines().next().unwrap_or("").to_string();
+                    row.status = RowStatus::Error;
+                }
+                Status::TimedOut(timeout) => {
+                    row.parts[0] = format!("timed out after {:?}", timeout);
+                    row.status = RowStatus::Timeout;
+                }
+            }
+            if row.status.is_failure() {
+                failures += 1;
+            }
+            rows.push(row);
+        }
+        Report {
+            year,
+            rows,
+            failures,
+            wall_time,
+        }
+    }
+
+    // e.g. "8 days, 7 pass, 1 failure(s), 1.23s in total."
+    fn summary(&self) -> String {
+        let passed = self
+            .rows
+            .iter()
+            .filter(|row| row.status == RowStatus::Pass)
+            .count();
+        format!(
+            "{} days, {} pass, {} failure(s), {:.2?} in total.",
+            self.rows.len(),
+            passed,
+            self.failures,
+            self.wall_time
+        )
+    }
+
+    /// Renders the report as a Markdown document with one table row per day.
+    pub fn to_markdown(&self) -> String {
+        let cell = |s: &str| s.replace('|', "\\|");
+        let mut markdown = format!("# Advent of Code {}\n\n{}\n\n", self.year, self.summary());
+        markdown.push_str("| Day | Title | Part 1 | Part 2 | Parse | Solve | Status |\n");
+        markdown.push_str("|----:|-------|--------|--------|------:|------:|--------|\n");
+        for row in self.rows.iter() {
+            markdown.push_str(&format!(
+                "| {} | {} | {} | {} | {} | {} | {} |\n",
+                row.day,
+                cell(&row.title),
+                cell(&row.parts[0]),
+                cell(&row.parts[1]),
+                row.parse_time,
+                row.solve_time,
+                row.status
+            ));
+        }
+        markdown
+    }
+
+    /// Renders the same report as a standalone HTML page.
+    pub fn to_html(&self) -> String {
+        let title = format!("Advent of Code {}", self.year);
+        let mut html = format!(
+            "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n\
+             </head>\n<body>\n<h1>{}</h1>\n<p>{}</p>\n<table>\n",
+            title,
+            title,
+            escape(&self.summary())
+        );
+        html.push_str(
+            "<tr><th>Day</th><th>Title</th><th>Part 1</th><th>Part 2</th><th>Parse</th>\
+             <th>Solve</th><th>Status</th></tr>\n",
+        );
+        for row in self.rows.iter() {
+            html.push_str(&format!(
+                "<tr class=\"{}\"><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td>\
+                 <td>{}</td><td>{}</td></tr>\n",
+                row.status,
+                row.day,
+                escape(&row.title),
+                escape(&row.parts[0]),
+                escape(&row.parts[1]),
+                escape(&row.parse_time),
+                escape(&row.solve_time),
+                row.status
+            ));
+        }
+        html.push_str("</table>\n</body>\n</html>\n");
+        html
+    }
+}
+
+// Escapes text for use in HTML.
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::testing::{day_result, solved};
+    use anyhow::format_err;
+
+    #[test]
+    fn test_to_markdown() {
+        let known =
+            KnownAnswers::parse("[2023.day01]\npart1 = 1\npart2 = 2\n[2023.day02]\npart1 = 3\n")
+                .unwrap();
+        let results = vec![
+            solved(1, &[1, 2]),
+            solved(2, &[4, 5]),
+            solved(3, &[6]),
+            day_result(4, Status::Failed(format_err!("Bad | input\nmore detail"))),
+        ];
+        let report = Report::new(2023, &results, &known, Duration::from_millis(12));
+        assert_eq!(2, report.failures);
+        assert_eq!(
+            "# Advent of Code 2023\n\n\
+             4 days, 1 pass, 2 failure(s), 12.00ms in total.\n\n\
+             | Day | Title | Part 1 | Part 2 | Parse | Solve | Status |\n\
+             |----:|-------|--------|--------|------:|------:|--------|\n\
+             | 1 | Trebuchet?! | 1 | 2 | 1.00ms | 4.00ms | pass |\n\
+             | 2 | Cube Conundrum | 4 (expected 3) | 5 | 1.00ms | 4.00ms | fail |\n\
+             | 3 | Gear Ratios | 6 |  | 1.00ms | 2.00ms | unverified |\n\
+             | 4 | Scratchcards | Bad \\| input |  |  |  | error |\n",
+            report.to_markdown()
+        );
+    }
+
+    #[test]
+    fn test_to_html() {
+        let results = vec![day_result(
+            1,
+            Status::Skipped("<none> not found".to_string()),
+        )];
+        let html = Report::new(2023, &results, &KnownAnswers::default(), Duration::ZERO).to_html();
+        assert!(html.contains("<h1>Advent of Code 2023</h1>"));
+        assert!(html.contains(
+            "<tr class=\"skipped\"><td>1</td><td>Trebuchet?!</td><td>&lt;none&gt; not found</td>"
+        ));
+        assert!(html.ends_with("</table>\n</body>\n</html>\n"));
+    }
+}
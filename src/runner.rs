@@ -0,0 +1,585 @@
+use crate::advent::{self, Solution};
+use crate::cancel::{self, CancelToken};
+use crate::config::Config;
+use crate::util::io;
+use anyhow::{format_err, Error};
+use serde_json::json;
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
+use std::sync::{mpsc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+use strum::{Display, EnumString};
+
+/// How run results are printed.
+#[derive(Clone, Copy, Debug, Default, Display, EnumString, Eq, PartialEq)]
+#[strum(serialize_all = "lowercase")]
+pub enum OutputFormat {
+    #[default]
+    Text,
+    Json,
+}
+
+/// Outcome of running a single day's solver.
+pub enum Status {
+    Solved(Solution),
+    Skipped(String),
+    Failed(Error),
+    TimedOut(Duration),
+}
+
+pub struct DayResult {
+    pub day: u32,
+    pub status: Status,
+    pub elapsed: Duration,
+}
+
+/// Runs one day of the configured year against its default input, reporting it as skipped if the
+/// input is missing.
+pub fn run_day(config: &Config, day: u32, timeout: Option<Duration>) -> DayResult {
+    let input_path = advent::input_path(config, day);
+    run_day_input(config.year, day, &input_path, None, timeout)
+}
+
+/// Runs one day of `year` (or one part of it) against the given input path.
+pub fn run_day_input(
+    year: u32,
+    day: u32,
+    input_path: &str,
+    part: Option<u32>,
+    timeout: Option<Duration>,
+) -> DayResult {
+    if input_path != io::STDIN_PATH && !Path::new(input_path).exists() {
+        return DayResult {
+            day,
+            status: Status::Skipped(format!("{} not found", input_path)),
+            elapsed: Duration::ZERO,
+        };
+    }
+    let start = Instant::now();
+    let status = solve_day(year, day, input_path, part, timeout);
+    DayResult {
+        day,
+        status,
+        elapsed: start.elapsed(),
+    }
+}
+
+/// Solves a day of `year`, giving up once `timeout` has passed. With a timeout the solver runs on
+/// its own thread and is cancelled (see `cancel::checkpoint`) when time is up; a solver that never
+/// checks is left to finish in the background.
+pub fn solve_day(
+    year: u32,
+    day: u32,
+    input_path: &str,
+    part: Option<u32>,
+    timeout: Option<Duration>,
+) -> Status {
+    let Some(timeout) = timeout else {
+        return match advent::solve(year, day, input_path, part) {
+            Ok(solution) => Status::Solved(solution),
+            Err(e) => Status::Failed(e),
+        };
+    };
+    let token = CancelToken::new();
+    let (sender, receiver) = mpsc::channel();
+    let worker_token = token.clone();
+    let input_path = input_path.to_string();
+    thread::spawn(move || {
+        let result = cancel::with_token(&worker_token, || {
+            advent::solve(year, day, &input_path, part)
+        });
+        // Nobody is listening any more if the solver timed out
+        let _ = sender.send(result);
+    });
+    match receiver.recv_timeout(timeout) {
+        Ok(Ok(solution)) => Status::Solved(solution),
+        Ok(Err(e)) => Status::Failed(e),
+        Err(_) => {
+            token.cancel();
+            Status::TimedOut(timeout)
+        }
+    }
+}
+
+/// The outcome of checking puzzle inputs for problems.
+pub struct InputCheck {
+    pub report: String,
+    pub problems: usize,
+}
+
+/// Checks the input of each day of `year` (see `advent::check`) without solving it. Missing inputs
+/// are reported as skipped; inputs that can't be read at all count as one problem.
+pub fn check_inputs(year: u32, inputs: &[(u32, String)]) -> InputCheck {
+    let mut report = String::new();
+    let mut problems = 0;
+    for (day, input_path) in inputs.iter() {
+        if input_path != io::STDIN_PATH && !Path::new(input_path).exists() {
+            report.push_str(&format!(
+                "Day {}: skipped ({} not found)\n",
+                day, input_path
+            ));
+            continue;
+        }
+        match advent::check(year, *day, input_path) {
+            Ok(found) if found.is_empty() => report.push_str(&format!("Day {}: ok\n", day)),
+            Ok(found) => {
+                problems += found.len();
+                let noun = if found.len() == 1 {
+                    "problem"
+                } else {
+                    "problems"
+                };
+                report.push_str(&format!("Day {}: {} {}\n", day, found.len(), noun));
+                for problem in found.iter() {
+                    report.push_str(&format!("\n{}\n", problem));
+                }
+            }
+            Err(e) => {
+                problems += 1;
+                report.push_str(&format!("Day {}: error: {}\n", day, e));
+            }
+        }
+    }
+    InputCheck { report, problems }
+}
+
+/// Runs every implemented day of the configured year on `jobs` worker threads, returning the
+/// results in day order.
+pub fn run_all(config: &Config, jobs: usize, timeout: Option<Duration>) -> Vec<DayResult> {
+    let days = advent::implemented_days(config.year);
+    let next = AtomicUsize::new(0);
+    let results = Mutex::new(Vec::with_capacity(days.len()));
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, days.len().max(1)) {
+            scope.spawn(|| {
+                while let Some(&day) = days.get(next.fetch_add(1, AtomicOrdering::Relaxed)) {
+                    let result = run_day(config, day, timeout);
+                    results.lock().unwrap().push(result);
+                }
+            });
+        }
+    });
+    let mut results = results.into_inner().unwrap();
+    results.sort_by_key(|result| result.day);
+    results
+}
+
+/// Lists every registered day of the configured year with its title and whether its default
+/// input file exists.
+pub fn list_table(config: &Config) -> String {
+    let registry = advent::registry(config.year);
+    let title_width = registry
+        .iter()
+        .map(|entry| entry.title.chars().count())
+        .max()
+        .unwrap_or(0);
+    let mut table = String::new();
+    for entry in registry.iter() {
+        let input_path = advent::input_path(config, entry.day);
+        let input = if Path::new(&input_path).exists() {
+            input_path
+        } else {
+            "(no input)".to_string()
+        };
+        table.push_str(&format!(
+            "{:>3}  {:<tw$}  {}\n",
+            entry.day,
+            entry.title,
+            input,
+            tw = title_width
+        ));
+    }
+    table
+}
+
+/// Renders results as a table with one row per answer (or per skip/error message).
+pub fn summary_table(results: &[DayResult]) -> String {
+    let mut rows: Vec<(String, String, String, String)> = Vec::new();
+    for result in results.iter() {
+        let (status, lines) = match &result.status {
+            Status::Solved(solution) => (
+                "ok",
+                solution
+                    .parts
+                    .iter()
+                    .map(|p| p.answer.to_string())
+                    .collect(),
+            ),
+            Status::Skipped(reason) => ("skipped", vec![reason.clone()]),
+            // Input errors span several lines (location and underline), one row each
+            Status::Failed(e) => ("error", e.to_string().lines().map(String::from).collect()),
+            Status::TimedOut(timeout) => {
+                ("timeout", vec![format!("timed out after {:?}", timeout)])
+            }
+        };
+        let time = match result.status {
+            Status::Skipped(_) => String::new(),
+            _ => format!("{:.2?}", result.elapsed),
+        };
+        for (i, line) in lines.into_iter().enumerate() {
+            if i == 0 {
+                rows.push((
+                    result.day.to_string(),
+                    status.to_string(),
+                    time.clone(),
+                    line,
+                ));
+            } else {
+                rows.push((String::new(), String::new(), String::new(), line));
+            }
+        }
+    }
+
+    let status_width = rows
+        .iter()
+        .map(|r| r.1.chars().count())
+        .max()
+        .unwrap_or(0)
+        .max(6);
+    let time_width = rows
+        .iter()
+        .map(|r| r.2.chars().count())
+        .max()
+        .unwrap_or(0)
+        .max(4);
+    let mut table = format!(
+        "Day | {:<sw$} | {:>tw$} | Answers\n",
+        "Status",
+        "Time",
+        sw = status_width,
+        tw = time_width
+    );
+    table.push_str(&format!(
+        "----+-{}-+-{}-+--------\n",
+        "-".repeat(status_width),
+        "-".repeat(time_width)
+    ));
+    for (day, status, time, line) in rows.iter() {
+        table.push_str(&format!(
+            "{:>3} | {:<sw$} | {:>tw$} | {}\n",
+            day,
+            status,
+            time,
+            line,
+            sw = status_width,
+            tw = time_width
+        ));
+    }
+    table
+}
+
+/// Renders a result as a single-line JSON object. Times are in milliseconds.
+pub fn json_line(result: &DayResult) -> String {
+    let millis = |d: Duration| d.as_secs_f64() * 1000.0;
+    let mut object = json!({
+        "day": result.day,
+        "elapsed_ms": millis(result.elapsed),
+    });
+    match &result.status {
+        Status::Solved(solution) => {
+            object["status"] = json!("ok");
+            object["parse_ms"] = json!(millis(solution.parse_time));
+            object["parts"] = solution
+                .parts
+                .iter()
+                .map(|p| {
+                    json!({
+                        "part": p.part,
+                        "label": p.answer.label,
+                        "answer": p.answer.value,
+                        "time_ms": millis(p.time),
+                    })
+                })
+                .collect();
+        }
+        Status::Skipped(reason) => {
+            object["status"] = json!("skipped");
+            object["error"] = json!(reason);
+        }
+        Status::Failed(e) => {
+            object["status"] = json!("error");
+            object["error"] = json!(e.to_string());
+        }
+        Status::TimedOut(timeout) => {
+            object["status"] = json!("timeout");
+            object["error"] = json!(format!("timed out after {:?}", timeout));
+        }
+    }
+    object.to_string()
+}
+
+/// Renders the parse and per-part times of a single run.
+pub fn timing_report(solution: &Solution) -> String {
+    let mut report = format!("Parse: {:.2?}\n", solution.parse_time);
+    for part in solution.parts.iter() {
+        report.push_str(&format!("Part {}: {:.2?}\n", part.part, part.time));
+    }
+    report
+}
+
+/// Summary statistics over repeated timings of the same phase.
+#[derive(Debug, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let n = sorted.len();
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+        // Nearest-rank percentile
+        let p95_rank = (n * 95).div_ceil(100);
+        Stats {
+            min: sorted[0],
+            median,
+            mean: sorted.iter().sum::<Duration>() / n as u32,
+            p95: sorted[p95_rank - 1],
+        }
+    }
+}
+
+/// Runs a day of `year` `iterations` times on the same input and summarizes the time taken by each
+/// phase (parse, each part, and the total), in that order.
+pub fn bench(
+    year: u32,
+    day: u32,
+    input_path: &str,
+    part: Option<u32>,
+    iterations: usize,
+) -> Result<Vec<(String, Stats)>, Error> {
+    if iterations == 0 {
+        return Err(format_err!("Benchmark needs at least one iteration."));
+    }
+    let input = io::read_file_as_lines(input_path)?;
+    let mut phases: Vec<(String, Vec<Duration>)> = Vec::new();
+    for i in 0..iterations {
+        let solution = advent::solve_input(year, day, &input, part)?;
+        let mut times = vec![("Parse".to_string(), solution.parse_time)];
+        for p in solution.parts.iter() {
+            times.push((format!("Part {}", p.part), p.time));
+        }
+        let total = times.iter().map(|(_, t)| *t).sum();
+        times.push(("Total".to_string(), total));
+
+        if i == 0 {
+            phases = times.into_iter().map(|(name, t)| (name, vec![t])).collect();
+        } else {
+            for ((_, samples), (_, t)) in phases.iter_mut().zip(times) {
+                samples.push(t);
+            }
+        }
+    }
+    Ok(phases
+        .into_iter()
+        .map(|(name, samples)| (name, Stats::from_samples(&samples)))
+        .collect())
+}
+
+pub fn bench_table(iterations: usize, phases: &[(String, Stats)]) -> String {
+    let mut table = format!(
+        "{} iterations\n{:<6} | {:>10} | {:>10} | {:>10} | {:>10}\n",
+        iterations, "Phase", "Min", "Median", "Mean", "p95"
+    );
+    table.push_str("-------+------------+------------+------------+-----------\n");
+    for (name, stats) in phases.iter() {
+        table.push_str(&format!(
+            "{:<6} | {:>10} | {:>10} | {:>10} | {:>10}\n",
+            name,
+            format!("{:.2?}", stats.min),
+            format!("{:.2?}", stats.median),
+            format!("{:.2?}", stats.mean),
+            format!("{:.2?}", stats.p95),
+        ));
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::advent::{Answer, PartSolution};
+    use crate::util::testing::TempPath;
+
+    #[test]
+    fn test_summary_table() {
+        let results = vec![
+            DayResult {
+                day: 1,
+                status: Status::Solved(Solution {
+                    parse_time: Duration::from_millis(2),
+                    parts: vec![
+                        PartSolution {
+                            part: 1,
+                            answer: Answer::new("First", 1),
+                            time: Duration::from_millis(4),
+                        },
+                        PartSolution {
+                            part: 2,
+                            answer: Answer::new("Second", 2),
+                            time: Duration::from_millis(6),
+                        },
+                    ],
+                }),
+                elapsed: Duration::from_millis(12),
+            },
+            DayResult {
+                day: 2,
+                status: Status::Failed(format_err!("bad input")),
+                elapsed: Duration::from_millis(3),
+            },
+            DayResult {
+                day: 10,
+                status: Status::Skipped("inputs/2023/day10.txt not found".to_string()),
+                elapsed: Duration::ZERO,
+            },
+        ];
+        assert_eq!(
+            "Day | Status  |    Time | Answers\n\
+             ----+---------+---------+--------\n  \
+               1 | ok      | 12.00ms | First: 1\n    \
+                 |         |         | Second: 2\n  \
+               2 | error   |  3.00ms | bad input\n \
+              10 | skipped |         | inputs/2023/day10.txt not found\n",
+            summary_table(&results)
+        );
+    }
+
+    #[test]
+    fn test_run_all_in_parallel() {
+        let results = run_all(&Config::default(), 4, None);
+        assert_eq!(
+            advent::implemented_days(2023),
+            results.iter().map(|r| r.day).collect::<Vec<_>>()
+        );
+        assert!(results[..8]
+            .iter()
+            .all(|r| matches!(r.status, Status::Solved(_))));
+    }
+
+    #[test]
+    fn test_list_table() {
+        let table = list_table(&Config::default());
+        assert_eq!(advent::registry(2023).len(), table.lines().count());
+        assert!(table.starts_with("  1  Trebuchet?!"));
+        assert!(table
+            .lines()
+            .next()
+            .unwrap()
+            .ends_with("inputs/2023/day01.txt"));
+    }
+
+    #[test]
+    fn test_json_line() {
+        let result = DayResult {
+            day: 3,
+            status: Status::Solved(Solution {
+                parse_time: Duration::from_millis(2),
+                parts: vec![PartSolution {
+                    part: 2,
+                    answer: Answer::new("Sum of gear ratios", 467835),
+                    time: Duration::from_micros(1500),
+                }],
+            }),
+            elapsed: Duration::from_millis(4),
+        };
+        assert_eq!(
+            "{\"day\":3,\"elapsed_ms\":4.0,\"parse_ms\":2.0,\"parts\":[{\"answer\":\"467835\",\
+             \"label\":\"Sum of gear ratios\",\"part\":2,\"time_ms\":1.5}],\"status\":\"ok\"}",
+            json_line(&result)
+        );
+
+        let result = DayResult {
+            day: 4,
+            status: Status::Failed(format_err!("Could not parse card: Card 1")),
+            elapsed: Duration::ZERO,
+        };
+        let value: serde_json::Value = serde_json::from_str(&json_line(&result)).unwrap();
+        assert_eq!("error", value["status"]);
+        assert_eq!("Could not parse card: Card 1", value["error"]);
+    }
+
+    #[test]
+    fn test_stats() {
+        let samples = (1..=20)
+            .rev()
+            .map(Duration::from_millis)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            Stats {
+                min: Duration::from_millis(1),
+                median: Duration::from_micros(10500),
+                mean: Duration::from_micros(10500),
+                p95: Duration::from_millis(19),
+            },
+            Stats::from_samples(&samples)
+        );
+    }
+
+    #[test]
+    fn test_bench() {
+        let phases = bench(2023, 6, "inputs/2023/day06.txt", Some(1), 3).unwrap();
+        let names = phases.iter().map(|(n, _)| n.as_str()).collect::<Vec<_>>();
+        assert_eq!(vec!["Parse", "Part 1", "Total"], names);
+    }
+
+    #[test]
+    fn test_check_inputs() {
+        let check = check_inputs(
+            2023,
+            &[
+                (4, "inputs/2023/day04.txt".to_string()),
+                (8, "inputs/2023/examples/day08-ex1.txt".to_string()),
+                (9, "inputs/2023/day99.txt".to_string()),
+            ],
+        );
+        assert_eq!(0, check.problems);
+        assert_eq!(
+            "Day 4: ok\nDay 8: ok\nDay 9: skipped (inputs/2023/day99.txt not found)\n",
+            check.report
+        );
+    }
+
+    #[test]
+    fn test_solve_day_times_out() {
+        // ZZZ can't be reached from AAA, so part 1 would walk forever
+        let path = TempPath::new("timeout.txt");
+        std::fs::write(
+            &path,
+            "L\n\nAAA = (BBB, BBB)\nBBB = (AAA, AAA)\nZZZ = (ZZZ, ZZZ)\n",
+        )
+        .unwrap();
+        let status = solve_day(
+            2023,
+            8,
+            path.to_str().unwrap(),
+            Some(1),
+            Some(Duration::from_millis(100)),
+        );
+        assert!(matches!(status, Status::TimedOut(_)));
+        let result = DayResult {
+            day: 8,
+            status,
+            elapsed: Duration::from_millis(100),
+        };
+        assert!(summary_table(&[result]).contains("timeout | 100.00ms | timed out after 100ms"));
+
+        let status = solve_day(
+            2023,
+            1,
+            "inputs/2023/day01.txt",
+            None,
+            Some(Duration::from_secs(60)),
+        );
+        assert!(matches!(status, Status::Solved(_)));
+    }
+}
@@ -0,0 +1,265 @@
+use crate::advent;
+use crate::config::Config;
+use crate::examples;
+use anyhow::{format_err, Error};
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::fs;
+use std::path::Path;
+
+lazy_static! {
+    static ref DAY_MOD_RE: Regex = Regex::new(r"(?m)^pub mod day\d+;\n").unwrap();
+    static ref YEAR_MOD_RE: Regex = Regex::new(r"(?m)^pub mod y\d+;\n").unwrap();
+}
+
+const SOLVER_TEMPLATE: &str = r#"use crate::advent::{AdventSolver, Answer};
+use anyhow::{format_err, Error};
+
+pub const DAY: u32 = __DAY__;
+pub const TITLE: &str = __TITLE__;
+
+#[derive(Default)]
+pub struct Solver {
+    lines: Vec<String>,
+}
+
+impl AdventSolver for Solver {
+    fn parse(&mut self, input: &[String]) -> Result<(), Error> {
+        self.lines = input.to_vec();
+        Ok(())
+    }
+
+    fn part_one(&self) -> Result<Answer, Error> {
+        Err(format_err!(
+            "Part 1 not implemented ({} lines of input).",
+            self.lines.len()
+        ))
+    }
+
+    fn part_two(&self) -> Result<Answer, Error> {
+        Err(format_err!(
+            "Part 2 not implemented ({} lines of input).",
+            self.lines.len()
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::io;
+    use lazy_static::lazy_static;
+
+    lazy_static! {
+        static ref EX_IN: Vec<String> =
+            io::read_file_as_lines("__EXAMPLE__").unwrap();
+    }
+
+    #[test]
+    #[ignore = "example answer not filled in yet"]
+    fn test_example_part_one() {
+        let mut solver = Solver::default();
+        solver.parse(&EX_IN).unwrap();
+        assert_eq!("", solver.part_one().unwrap().value);
+    }
+}
+"#;
+
+/// Creates the files for a new day of the configured year under `root` (the crate directory) and
+/// registers its module: `src/advent/yYYYY/dayNN.rs`, an empty input (`inputs/YYYY/dayNN.txt` by
+/// default) and `inputs/YYYY/examples/dayNN-ex1.txt`. The year's module is created and registered
+/// too, if this is its first day.
+/// Returns the paths written. Refuses to touch a day that already exists.
+pub fn new_day(
+    root: &Path,
+    config: &Config,
+    day: u32,
+    title: Option<&str>,
+) -> Result<Vec<String>, Error> {
+    if !(1..=25).contains(&day) {
+        return Err(format_err!("Day must be between 1 and 25, not {}.", day));
+    }
+    let year = config.year;
+    let year_module = format!("y{}", year);
+    let module = format!("day{:02}", day);
+    let source_path = format!("src/advent/{}/{}.rs", year_module, module);
+    let input_path = advent::input_path(config, day);
+    let example_path = format!("{}/{}-ex1.txt", examples::examples_dir(year), module);
+    for path in [&source_path, &input_path, &example_path] {
+        if root.join(path).exists() {
+            return Err(format_err!("{} already exists.", path));
+        }
+    }
+
+    let mut written = vec![
+        source_path.clone(),
+        input_path.clone(),
+        example_path.clone(),
+    ];
+    let year_mod_path = format!("src/advent/{}/mod.rs", year_module);
+    let year_source = match fs::read_to_string(root.join(&year_mod_path)) {
+        Ok(source) => source,
+        Err(_) => {
+            // The year's first day: its module needs creating and registering as well
+            let advent_mod_path = root.join("src/advent/mod.rs");
+            let registered = register_year(&fs::read_to_string(&advent_mod_path)?, year)?;
+            fs::create_dir_all(root.join("src/advent").join(&year_module))?;
+            fs::write(advent_mod_path, registered)?;
+            written.push("src/advent/mod.rs".to_string());
+            year_mod_source(year)
+        }
+    };
+    let registered = register_module(&year_source, &module)?;
+
+    let default_title = format!("Day {}", day);
+    let source = SOLVER_TEMPLATE
+        .replace("__DAY__", &day.to_string())
+        .replace("__EXAMPLE__", &example_path)
+        .replace(
+            "__TITLE__",
+            &format!("{:?}", title.unwrap_or(&default_title)),
+        );
+    fs::write(root.join(&source_path), source)?;
+    for path in [&input_path, &example_path] {
+        if let Some(dir) = root.join(path).parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(root.join(path), "")?;
+    }
+    fs::write(root.join(&year_mod_path), registered)?;
+    written.push(year_mod_path);
+
+    Ok(written)
+}
+
+/// The source of a new year's module, before any days are declared in it.
+fn year_mod_source(year: u32) -> String {
+    format!(
+        "//! Advent of Code {}. Every day declared here is listed in `REGISTRY` (generated by \
+         `build.rs`).\n\ninclude!(concat!(env!(\"OUT_DIR\"), \"/y{}_registry.rs\"));\n",
+        year, year
+    )
+}
+
+/// Declares `module` in the source of a year's module, keeping the declarations sorted. That's
+/// all it takes to register it, since `build.rs` lists every declared day in `REGISTRY`.
+fn register_module(mod_source: &str, module: &str) -> Result<String, Error> {
+    declare_module(mod_source, &DAY_MOD_RE, "include!", module)
+}
+
+/// Declares the year's module in the source of `src/advent/mod.rs`, keeping the declarations
+/// sorted. As with days, `build.rs` then lists it in `YEARS`.
+fn register_year(mod_source: &str, year: u32) -> Result<String, Error> {
+    declare_module(mod_source, &YEAR_MOD_RE, "include!", &format!("y{}", year))
+}
+
+/// Adds `pub mod <module>;` among the declarations matched by `re`, keeping them sorted, or (if
+/// there are none yet) just before the first `name` (e.g. a macro invocation).
+fn declare_module(source: &str, re: &Regex, name: &str, module: &str) -> Result<String, Error> {
+    let mut declaration = format!("pub mod {};\n", module);
+    let declarations = re.find_iter(source).collect::<Vec<_>>();
+    if declarations.iter().any(|d| d.as_str() == declaration) {
+        return Err(format_err!("{} is already registered.", module));
+    }
+    let at = match declarations
+        .iter()
+        .find(|d| d.as_str() > declaration.as_str())
+    {
+        Some(next) => next.start(),
+        None => match declarations.last() {
+            Some(last) => last.end(),
+            None => {
+                declaration.push('\n');
+                source.find(name).ok_or(format_err!(
+                    "Could not find {} to declare {} before",
+                    name,
+                    module
+                ))?
+            }
+        },
+    };
+    Ok(format!("{}{}{}", &source[..at], declaration, &source[at..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::testing::TempPath;
+
+    #[test]
+    fn test_register_module() {
+        let source = "pub mod day01;\npub mod day03;\n\ninclude!(\"registry.rs\");\n";
+        assert_eq!(
+            "pub mod day01;\npub mod day02;\npub mod day03;\n\ninclude!(\"registry.rs\");\n",
+            register_module(source, "day02").unwrap()
+        );
+        assert_eq!(
+            "pub mod day01;\npub mod day03;\npub mod day10;\n\ninclude!(\"registry.rs\");\n",
+            register_module(source, "day10").unwrap()
+        );
+        assert!(register_module(source, "day03").is_err());
+        assert_eq!(
+            "//! Days.\n\npub mod day01;\n\ninclude!(\"registry.rs\");\n",
+            register_module("//! Days.\n\ninclude!(\"registry.rs\");\n", "day01").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_register_year() {
+        let source = "pub mod y2022;\n\ninclude!(\"years.rs\");\n";
+        assert_eq!(
+            "pub mod y2022;\npub mod y2023;\n\ninclude!(\"years.rs\");\n",
+            register_year(source, 2023).unwrap()
+        );
+        assert_eq!(
+            "pub mod y2021;\npub mod y2022;\n\ninclude!(\"years.rs\");\n",
+            register_year(source, 2021).unwrap()
+        );
+        assert!(register_year(source, 2022).is_err());
+    }
+
+    #[test]
+    fn test_new_day() {
+        let root = TempPath::new("scaffold");
+        let config = Config::default();
+        fs::create_dir_all(root.join("src/advent")).unwrap();
+        fs::write(
+            root.join("src/advent/mod.rs"),
+            "pub mod y2022;\n\ninclude!(\"years.rs\");\n",
+        )
+        .unwrap();
+
+        // The year's first day also creates and registers its module
+        let written = new_day(&root, &config, 9, Some("Mirage Maintenance")).unwrap();
+        assert_eq!(5, written.len());
+        let source = fs::read_to_string(root.join("src/advent/y2023/day09.rs")).unwrap();
+        assert!(source.contains("pub const DAY: u32 = 9;"));
+        assert!(source.contains("pub const TITLE: &str = \"Mirage Maintenance\";"));
+        assert!(source.contains("inputs/2023/examples/day09-ex1.txt"));
+        assert!(root.join("inputs/2023/day09.txt").exists());
+        assert_eq!(
+            "pub mod y2022;\npub mod y2023;\n\ninclude!(\"years.rs\");\n",
+            fs::read_to_string(root.join("src/advent/mod.rs")).unwrap()
+        );
+        let year_source = fs::read_to_string(root.join("src/advent/y2023/mod.rs")).unwrap();
+        assert!(year_source.starts_with("//! Advent of Code 2023."));
+        assert!(year_source.ends_with(
+            "\n\npub mod day09;\n\ninclude!(concat!(env!(\"OUT_DIR\"), \"/y2023_registry.rs\"));\n"
+        ));
+
+        assert_eq!(4, new_day(&root, &config, 2, None).unwrap().len());
+        assert!(fs::read_to_string(root.join("src/advent/y2023/mod.rs"))
+            .unwrap()
+            .contains("\n\npub mod day02;\npub mod day09;\n\ninclude!"));
+
+        // Titles are written as string literals, escaped as needed
+        new_day(&root, &config, 3, Some("The \"Gear\" Ratios")).unwrap();
+        assert!(fs::read_to_string(root.join("src/advent/y2023/day03.rs"))
+            .unwrap()
+            .contains(r#"pub const TITLE: &str = "The \"Gear\" Ratios";"#));
+
+        // Existing days are never overwritten.
+        assert!(new_day(&root, &config, 9, None).is_err());
+        assert!(new_day(&root, &config, 26, None).is_err());
+    }
+}
@@ -0,0 +1,242 @@
+use crate::aoc::Verdict;
+use anyhow::{format_err, Error};
+use serde_json::json;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// Default location of the submission log, one JSON object per line.
+pub const SUBMISSIONS_PATH: &str = "submissions.jsonl";
+
+/// One answer submitted to the site and what it said back.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Attempt {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Unix time (seconds) of the submission.
+    pub time: u64,
+    /// Seconds the site asked us to wait before submitting again.
+    pub cooldown: Option<u64>,
+}
+
+impl Attempt {
+    fn to_json(&self) -> String {
+        json!({
+            "year": self.year,
+            "day": self.day,
+            "part": self.part,
+            "answer": self.answer,
+            "verdict": self.verdict.to_string(),
+            "time": self.time,
+            "cooldown": self.cooldown,
+        })
+        .to_string()
+    }
+
+    fn from_json(line: &str) -> Result<Attempt, Error> {
+        let value: serde_json::Value = serde_json::from_str(line)?;
+        let field = |name: &str| {
+            value.get(name).ok_or(format_err!(
+                "Submission log entry missing {}: {}",
+                name,
+                line
+            ))
+        };
+        let number = |name: &str| {
+            field(name)?
+                .as_u64()
+                .ok_or(format_err!("Expected {} to be a number: {}", name, line))
+        };
+        let string = |name: &str| {
+            field(name)?
+                .as_str()
+                .map(|s| s.to_string())
+                .ok_or(format_err!("Expected {} to be a string: {}", name, line))
+        };
+        Ok(Attempt {
+            year: number("year")? as u32,
+            day: number("day")? as u32,
+            part: number("part")? as u32,
+            answer: string("answer")?,
+            verdict: Verdict::from_str(&string("verdict")?)?,
+            time: number("time")?,
+            cooldown: value.get("cooldown").and_then(|c| c.as_u64()),
+        })
+    }
+}
+
+/// Every answer submitted so far, used to avoid pointless or premature resubmissions.
+pub struct SubmissionLog {
+    path: PathBuf,
+    attempts: Vec<Attempt>,
+}
+
+impl SubmissionLog {
+    /// Loads the log at `path`, which need not exist yet.
+    pub fn load(path: &Path) -> Result<SubmissionLog, Error> {
+        let attempts = if path.exists() {
+            fs::read_to_string(path)?
+                .lines()
+                .filter(|line| !line.trim().is_empty())
+                .map(Attempt::from_json)
+                .collect::<Result<Vec<_>, _>>()?
+        } else {
+            Vec::new()
+        };
+        Ok(SubmissionLog {
+            path: path.to_path_buf(),
+            attempts,
+        })
+    }
+
+    /// Checks whether submitting `answer` at Unix time `now` could possibly be useful. Returns an
+    /// error explaining why not if the part is already solved, the answer (or one beyond a known
+    /// too-high/too-low bound) was already rejected, or the site's cooldown hasn't expired.
+    pub fn check(
+        &self,
+        year: u32,
+        day: u32,
+        part: u32,
+        answer: &str,
+        now: u64,
+    ) -> Result<(), Error> {
+        let attempts = self
+            .attempts
+            .iter()
+            .filter(|a| a.year == year && a.day == day && a.part == part)
+            .collect::<Vec<_>>();
+        let numeric = answer.parse::<i64>().ok();
+        for attempt in attempts.iter() {
+            let previous = attempt.answer.parse::<i64>().ok();
+            match attempt.verdict {
+                Verdict::Correct => {
+                    return Err(format_err!(
+                        "Day {} part {} was already solved with {}.",
+                        day,
+                        part,
+                        attempt.answer
+                    ));
+                }
+                Verdict::TooHigh | Verdict::TooLow | Verdict::Incorrect
+                    if attempt.answer == answer =>
+                {
+                    return Err(format_err!(
+                        "{} was already rejected ({}).",
+                        answer,
+                        attempt.verdict
+                    ));
+                }
+                Verdict::TooHigh if matches!((numeric, previous), (Some(a), Some(p)) if a >= p) => {
+                    return Err(format_err!(
+                        "{} is not below {}, which was too high.",
+                        answer,
+                        attempt.answer
+                    ));
+                }
+                Verdict::TooLow if matches!((numeric, previous), (Some(a), Some(p)) if a <= p) => {
+                    return Err(format_err!(
+                        "{} is not above {}, which was too low.",
+                        answer,
+                        attempt.answer
+                    ));
+                }
+                _ => {}
+            }
+        }
+        if let Some(last) = attempts.last() {
+            let ready_at = last.time + last.cooldown.unwrap_or(0);
+            if now < ready_at {
+                return Err(format_err!(
+                    "Still cooling down; wait {}s before submitting again.",
+                    ready_at - now
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Appends an attempt to the log file.
+    pub fn record(&mut self, attempt: Attempt) -> Result<(), Error> {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        writeln!(file, "{}", attempt.to_json())?;
+        self.attempts.push(attempt);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc::tests::serve;
+    use crate::aoc::Client;
+    use crate::util::testing::TempPath;
+
+    fn attempt(answer: &str, verdict: Verdict, time: u64, cooldown: Option<u64>) -> Attempt {
+        Attempt {
+            year: 2023,
+            day: 5,
+            part: 2,
+            answer: answer.to_string(),
+            verdict,
+            time,
+            cooldown,
+        }
+    }
+
+    #[test]
+    fn test_check() {
+        let path = TempPath::new("submissions.jsonl");
+        let mut log = SubmissionLog::load(&path).unwrap();
+        assert!(log.check(2023, 5, 2, "100", 1000).is_ok());
+
+        log.record(attempt("100", Verdict::TooHigh, 1000, Some(60)))
+            .unwrap();
+        // Cooldown from the last attempt
+        assert!(log.check(2023, 5, 2, "50", 1030).is_err());
+        assert!(log.check(2023, 5, 2, "50", 1060).is_ok());
+        // Known-wrong answers and answers beyond a known bound
+        assert!(log.check(2023, 5, 2, "100", 2000).is_err());
+        assert!(log.check(2023, 5, 2, "150", 2000).is_err());
+        // Other parts and years are unaffected
+        assert!(log.check(2023, 5, 1, "100", 1000).is_ok());
+        assert!(log.check(2022, 5, 2, "100", 1000).is_ok());
+
+        log.record(attempt("10", Verdict::TooLow, 2000, None))
+            .unwrap();
+        assert!(log.check(2023, 5, 2, "5", 3000).is_err());
+        assert!(log.check(2023, 5, 2, "42", 3000).is_ok());
+
+        log.record(attempt("42", Verdict::Correct, 3000, None))
+            .unwrap();
+        assert!(log.check(2023, 5, 2, "43", 4000).is_err());
+
+        // The log round-trips through its file.
+        let reloaded = SubmissionLog::load(&path).unwrap();
+        assert_eq!(log.attempts, reloaded.attempts);
+    }
+
+    #[test]
+    fn test_submit_and_record_against_mock_server() {
+        let (base_url, server) = serve(vec![(
+            200,
+            "<p>You gave an answer too recently. You have 30s left to wait.</p>".to_string(),
+        )]);
+        let client = Client::new(&base_url, "abc123");
+        let response = client.submit_answer(2023, 5, 2, "46").unwrap();
+
+        let path = TempPath::new("mock-submit.jsonl");
+        let mut log = SubmissionLog::load(&path).unwrap();
+        log.record(attempt("46", response.verdict, 1000, response.cooldown))
+            .unwrap();
+        assert!(log.check(2023, 5, 2, "46", 1010).is_err());
+        assert!(log.check(2023, 5, 2, "46", 1030).is_ok());
+        server.join().unwrap();
+    }
+}
@@ -1,11 +1,27 @@
-use anyhow::Error;
+use anyhow::{format_err, Error};
 use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::io::{self, BufRead, BufReader};
 
+/// Path that means "read from standard input" when passed as an input path.
+pub const STDIN_PATH: &str = "-";
+
+/// Opens an input for line-oriented reading. A path of "-" reads from standard input.
+pub fn open_input(path: &str) -> Result<Box<dyn BufRead>, Error> {
+    if path == STDIN_PATH {
+        Ok(Box::new(BufReader::new(io::stdin())))
+    } else {
+        let file = File::open(path).map_err(|e| format_err!("Could not open {}: {}", path, e))?;
+        Ok(Box::new(BufReader::new(file)))
+    }
+}
+
+/// Reads an entire input (file or "-" for stdin) into a vector of lines.
 pub fn read_file_as_lines(path: &str) -> Result<Vec<String>, Error> {
-    Ok(BufReader::new(File::open(path)?)
-        .lines()
-        .collect::<Result<Vec<_>, _>>()?)
+    read_lines(open_input(path)?)
+}
+
+pub fn read_lines<R: BufRead>(reader: R) -> Result<Vec<String>, Error> {
+    Ok(reader.lines().collect::<Result<Vec<_>, _>>()?)
 }
 
 pub fn space_separated_numbers(s: &str) -> Result<Vec<u64>, Error> {
@@ -13,3 +29,20 @@ pub fn space_separated_numbers(s: &str) -> Result<Vec<u64>, Error> {
         .map(|s| s.parse::<u64>())
         .collect::<Result<Vec<_>, _>>()?)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn test_read_lines() {
+        let lines = read_lines(Cursor::new("Time: 7 15\nDistance: 9 40\n")).unwrap();
+        assert_eq!(vec!["Time: 7 15", "Distance: 9 40"], lines);
+    }
+
+    #[test]
+    fn test_open_missing_input() {
+        assert!(open_input("inputs/no-such-file.txt").is_err());
+    }
+}
@@ -1,2 +1,4 @@
 pub mod conversions;
 pub mod io;
+#[cfg(test)]
+pub mod testing;
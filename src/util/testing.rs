@@ -0,0 +1,70 @@
+//! Helpers shared by the unit tests.
+
+use crate::advent::{Answer, PartSolution, Solution};
+use crate::runner::{DayResult, Status};
+use std::env;
+use std::fs;
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::time::Duration;
+
+/// A path in the temp directory, unique to this process, that is removed (whether it became a
+/// file or a directory) when dropped, so tests clean up after themselves even when they fail.
+pub struct TempPath(PathBuf);
+
+impl TempPath {
+    /// e.g. `TempPath::new("watch.txt")` for `$TMPDIR/advent-1234-watch.txt`.
+    pub fn new(name: &str) -> TempPath {
+        TempPath(env::temp_dir().join(format!("advent-{}-{}", process::id(), name)))
+    }
+}
+
+impl Deref for TempPath {
+    type Target = Path;
+
+    fn deref(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl AsRef<Path> for TempPath {
+    fn as_ref(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for TempPath {
+    fn drop(&mut self) {
+        // Nothing may have been written to it
+        let _ = fs::remove_dir_all(&self.0).or_else(|_| fs::remove_file(&self.0));
+    }
+}
+
+/// A result for `day` that took 5ms in all.
+pub fn day_result(day: u32, status: Status) -> DayResult {
+    DayResult {
+        day,
+        status,
+        elapsed: Duration::from_millis(5),
+    }
+}
+
+/// A solved day whose parts answered `values` in order, taking 1ms to parse and 2ms per part.
+pub fn solved(day: u32, values: &[u64]) -> DayResult {
+    day_result(
+        day,
+        Status::Solved(Solution {
+            parse_time: Duration::from_millis(1),
+            parts: values
+                .iter()
+                .enumerate()
+                .map(|(i, v)| PartSolution {
+                    part: i as u32 + 1,
+                    answer: Answer::new("Answer", v),
+                    time: Duration::from_millis(2),
+                })
+                .collect(),
+        }),
+    )
+}
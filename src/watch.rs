@@ -0,0 +1,145 @@
+use crate::advent::{self, Answer};
+use crate::examples::{self, Example};
+use crate::runner;
+use std::fs;
+use std::path::PathBuf;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+/// How often file modification times are checked.
+pub const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Tracks modification times of a set of files. Polling keeps this portable: no platform file
+/// notification APIs are needed.
+pub struct Watcher {
+    files: Vec<(PathBuf, Option<SystemTime>)>,
+}
+
+impl Watcher {
+    pub fn new(paths: Vec<PathBuf>) -> Watcher {
+        let files = paths
+            .into_iter()
+            .map(|path| {
+                let mtime = Self::mtime(&path);
+                (path, mtime)
+            })
+            .collect();
+        Watcher { files }
+    }
+
+    /// Returns the files whose modification time changed (including appearing or disappearing)
+    /// since the last poll.
+    pub fn poll(&mut self) -> Vec<PathBuf> {
+        let mut changed = Vec::new();
+        for (path, last) in self.files.iter_mut() {
+            let mtime = Self::mtime(path);
+            if mtime != *last {
+                *last = mtime;
+                changed.push(path.clone());
+            }
+        }
+        changed
+    }
+
+    fn mtime(path: &PathBuf) -> Option<SystemTime> {
+        fs::metadata(path).and_then(|m| m.modified()).ok()
+    }
+}
+
+/// Renders answers, marking any whose value differs from the previous run's answer for the same
+/// position.
+pub fn answer_diff(previous: &[Answer], current: &[Answer]) -> String {
+    let mut report = String::new();
+    for (i, answer) in current.iter().enumerate() {
+        match previous.get(i) {
+            Some(old) if old.value != answer.value => {
+                report.push_str(&format!("{} (was {})\n", answer, old.value));
+            }
+            _ => report.push_str(&format!("{}\n", answer)),
+        }
+    }
+    report
+}
+
+/// Runs a day of `year` and then re-runs it whenever its input (or, if given, any of its example
+/// files) changes. Never returns.
+pub fn watch(
+    year: u32,
+    day: u32,
+    input_path: &str,
+    part: Option<u32>,
+    examples: Option<Vec<Example>>,
+) {
+    let mut paths = vec![PathBuf::from(input_path)];
+    if let Some(examples) = &examples {
+        paths.extend(examples.iter().map(|e| PathBuf::from(e.path())));
+    }
+    let mut watcher = Watcher::new(paths);
+    let mut previous: Vec<Answer> = Vec::new();
+    println!("Watching {} for changes...", input_path);
+    loop {
+        if let Some(examples) = &examples {
+            print!("{}", examples::run(examples, part).report);
+        }
+        match advent::solve(year, day, input_path, part) {
+            Ok(solution) => {
+                let answers = solution.answers();
+                print!("{}", answer_diff(&previous, &answers));
+                print!("{}", runner::timing_report(&solution));
+                previous = answers;
+            }
+            Err(e) => println!("error: {}", e),
+        }
+
+        let changed = loop {
+            thread::sleep(POLL_INTERVAL);
+            let changed = watcher.poll();
+            if !changed.is_empty() {
+                break changed;
+            }
+        };
+        for path in changed.iter() {
+            println!("\n{} changed", path.display());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::testing::TempPath;
+    use std::fs::File;
+
+    #[test]
+    fn test_watcher_poll() {
+        let path = TempPath::new("watch.txt");
+        let missing = TempPath::new("watch.missing");
+        fs::write(&path, "1").unwrap();
+        let mut watcher = Watcher::new(vec![path.to_path_buf(), missing.to_path_buf()]);
+        assert!(watcher.poll().is_empty());
+
+        let later = SystemTime::now() + Duration::from_secs(10);
+        File::options()
+            .write(true)
+            .open(&path)
+            .unwrap()
+            .set_modified(later)
+            .unwrap();
+        assert_eq!(vec![path.to_path_buf()], watcher.poll());
+        assert!(watcher.poll().is_empty());
+
+        fs::write(&missing, "2").unwrap();
+        assert_eq!(vec![missing.to_path_buf()], watcher.poll());
+    }
+
+    #[test]
+    fn test_answer_diff() {
+        let previous = vec![Answer::new("Part one", 35), Answer::new("Part two", 46)];
+        let current = vec![Answer::new("Part one", 35), Answer::new("Part two", 47)];
+        assert_eq!(
+            "Part one: 35\nPart two: 47 (was 46)\n",
+            answer_diff(&previous, &current)
+        );
+        assert_eq!("Part one: 35\nPart two: 46\n", answer_diff(&[], &previous));
+    }
+}
@@ -0,0 +1,56 @@
+use advent2023::advent::y2023::day05::Almanac;
+use advent2023::advent::y2023::day07::{CamelHand, CamelHandType};
+use advent2023::advent::y2023::day08::DesertMap;
+use advent2023::advent::{self, Answer};
+use advent2023::util::io;
+
+#[test]
+fn test_registry_solvers_through_trait() {
+    let entry = advent::entry_for(2023, 4).unwrap();
+    assert_eq!("Scratchcards", entry.title);
+
+    let mut solver = entry.create();
+    solver
+        .parse(&io::read_file_as_lines("inputs/2023/examples/day04-ex1.txt").unwrap())
+        .unwrap();
+    assert_eq!(
+        Answer::new("Sum of card point values", 13),
+        solver.part_one().unwrap()
+    );
+    assert_eq!(
+        Answer::new("Card count after propagation", 30),
+        solver.part_two().unwrap()
+    );
+}
+
+#[test]
+fn test_solve_by_day() {
+    let solution = advent::solve(2023, 2, "inputs/2023/examples/day02-ex1.txt", None).unwrap();
+    assert_eq!(
+        vec!["8", "2286"],
+        solution
+            .answers()
+            .iter()
+            .map(|a| a.value.as_str())
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_domain_types() {
+    let almanac =
+        Almanac::new(&io::read_file_as_lines("inputs/2023/examples/day05-ex1.txt").unwrap())
+            .unwrap();
+    assert_eq!(vec![79, 14, 55, 13], almanac.seeds_to_plant);
+    assert_eq!(82, almanac.location_for_seed(79));
+
+    let map =
+        DesertMap::new(&io::read_file_as_lines("inputs/2023/examples/day08-ex3.txt").unwrap())
+            .unwrap();
+    assert_eq!(6, map.parallel_steps_to_z().unwrap());
+
+    let mut hand = "KTJJT".parse::<CamelHand>().unwrap();
+    assert_eq!(CamelHandType::TwoPair, hand.hand_type());
+    hand.jokers_wild();
+    assert_eq!(CamelHandType::FourOfAKind, hand.hand_type());
+}